@@ -1,9 +1,14 @@
 #![no_std]
 
+mod verifier;
+
 use soroban_sdk::{
-    contract, contractimpl, contracttype, contracterror, symbol_short, Address, Env, Bytes, Vec, panic_with_error
+    contract, contractimpl, contracttype, contracterror, symbol_short, vec, Address, Env, Bytes, BytesN, IntoVal, Vec,
+    panic_with_error
 };
 
+use verifier::{parse_proof, parse_public_signals, parse_verification_key, verify_groth16_proof};
+
 // ---------------------------------------------------------------------------
 // Game Hub Interface
 // ---------------------------------------------------------------------------
@@ -39,6 +44,12 @@ pub enum Error {
     NotYourTurn = 8,
     InvalidMove = 9,
     InvalidCard = 10,
+    InvalidProofFormat = 11,
+    VerificationKeyNotSet = 12,
+    InvalidMerkleProof = 13,
+    TimeoutNotReached = 14,
+    InvalidRules = 15,
+    ContractPaused = 16,
 }
 
 // ---------------------------------------------------------------------------
@@ -53,6 +64,7 @@ pub enum Phase {
     Reveal,
     Playing,
     Finished,
+    Tied,      // Ended by mutual agreement, no winner reported
 }
 
 #[contracttype]
@@ -62,12 +74,109 @@ pub struct Card {
     pub rank: u32,   // 1-10
 }
 
+/// Ability cards, dealt into decks as card IDs `SUIT_CARD_COUNT + ability`
+///
+/// Drawing one never busts; it sits in the play area until the player resolves
+/// it with `play_ability`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Ability {
+    Hook = 0,    // Put one of your banked cards back into play
+    Cannon = 1,  // Discard one of the opponent's banked cards
+    Sword = 2,   // Steal one of the opponent's banked cards into play
+    Map = 3,     // Put a card from the discard pile into play
+    Oracle = 4,  // If the next card would bust, it is discarded instead
+    Anchor = 5,  // Cards played before the Anchor are banked even on a bust
+    Key = 6,     // Key and Chest banked together double the turn's score
+    Chest = 7,
+}
+
+/// Groth16 proof for BN254 curve
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Groth16Proof {
+    pub pi_a: BytesN<64>,      // G1 point (2 * 32 bytes)
+    pub pi_b: BytesN<128>,     // G2 point (4 * 32 bytes)
+    pub pi_c: BytesN<64>,      // G1 point (2 * 32 bytes)
+}
+
+/// ZK Proof with public inputs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZKProof {
+    pub proof: Groth16Proof,
+    pub public_inputs: Vec<BytesN<32>>,
+}
+
+/// Merkle path from a deck leaf up to the deck root
+///
+/// Leaves are `SHA256(index || card_id)` (4-byte big-endian each) and inner
+/// nodes `SHA256(left || right)`; `siblings` run from the leaf level upward.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleProof {
+    pub index: u32,  // Deck position, must equal the player's cards drawn so far
+    pub siblings: Vec<BytesN<32>>,
+}
+
+/// Verification key for Groth16
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationKey {
+    pub alpha: BytesN<64>,
+    pub beta: BytesN<128>,
+    pub gamma: BytesN<128>,
+    pub delta: BytesN<128>,
+    pub ic: Vec<BytesN<64>>,  // IC points for public inputs
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TurnOutcome {
+    Banked,
+    Busted,
+}
+
+/// One finished turn in a session's history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TurnRecord {
+    pub turn_number: u32,
+    pub player: Address,
+    pub cards: Vec<u32>,       // Card IDs in play when the turn ended, busting card last
+    pub outcome: TurnOutcome,
+    pub score_delta: u32,      // Points banked (on a bust, those an Anchor saved)
+}
+
+/// Per-session rules, e.g. for short games or one-bust hardcore mode
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRules {
+    pub win_score: u32,
+    pub max_busts: u32,   // Busts that lose the game
+    pub deck_size: u32,   // Cards in each committed deck
+}
+
+/// Admin limits on the per-session rules
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRuleBounds {
+    pub min_win_score: u32,
+    pub max_win_score: u32,
+    pub max_busts: u32,
+    pub max_deck_size: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameState {
     pub session_id: u32,
     pub player1: Address,
     pub player2: Address,
+    pub p1_points: i128,  // Points staked through the Game Hub
+    pub p2_points: i128,
+    pub rules: GameRules,
     pub p1_deck_root: Bytes,
     pub p2_deck_root: Bytes,
     pub p1_commit: Option<Bytes>,
@@ -81,27 +190,55 @@ pub struct GameState {
     pub p2_busts: u32,
     pub p1_cards_drawn: u32,  // Cards drawn from deck
     pub p2_cards_drawn: u32,
+    pub p1_bank: Vec<u32>,         // Suit card IDs banked by P1
+    pub p2_bank: Vec<u32>,
+    pub discard: Vec<u32>,         // Suit cards lost to busts and Cannons
     pub active_player: Address,
     pub turn_cards: Vec<u32>,      // Card IDs drawn this turn
     pub turn_suits_mask: u32,      // 4-bit mask of suits this turn
     pub turn_score: u32,          // Points accumulated this turn
+    pub pending_ability: Option<Ability>,  // Drawn ability awaiting play_ability
+    pub turn_anchor: u32,          // Turn cards protected by an Anchor
+    pub turn_oracle: bool,         // Oracle guards the next card this turn
     pub phase: Phase,
     pub turn_number: u32,
+    pub last_action_ledger: u32,  // Ledger of the active player's last action
+    pub draw_offer: Option<Address>,  // Player offering a tie this turn
+    pub turns_logged: u32,        // Entries in the turn history
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     GameState(u32),
+    Turn(u32, u32),        // (session_id, index) -> TurnRecord
     GameHub,
     Admin,
     Initialized,
+    DrawVerificationKey,
+    GameRuleBounds,
+    Paused,                // bool, set by the admin to halt gameplay
 }
 
 const GAME_TTL_LEDGERS: u32 = 518_400; // ~30 days
-const WIN_SCORE: u32 = 60;
-const MAX_BUSTS: u32 = 3;
-const DECK_SIZE: u32 = 40;
+const SUIT_CARD_COUNT: u32 = 40;
+const ABILITY_COUNT: u32 = 8;
+const FULL_DECK_SIZE: u32 = SUIT_CARD_COUNT + ABILITY_COUNT; // Every distinct card once
+
+// Rules for sessions started without their own
+const DEFAULT_WIN_SCORE: u32 = 60;
+const DEFAULT_MAX_BUSTS: u32 = 3;
+const DEFAULT_DECK_SIZE: u32 = FULL_DECK_SIZE;
+
+// Default bounds on per-session rules
+const DEFAULT_MIN_WIN_SCORE: u32 = 20;
+const DEFAULT_MAX_WIN_SCORE: u32 = 200;
+const DEFAULT_MAX_BUST_LIMIT: u32 = 10;
+const DEFAULT_MAX_DECK_SIZE: u32 = FULL_DECK_SIZE;
+
+// Timeouts and paging
+const TURN_TIMEOUT_LEDGERS: u32 = 720; // ~1 hour
+const MAX_TURNS_PAGE: u32 = 50;
 
 // ---------------------------------------------------------------------------
 // Helper Functions
@@ -109,7 +246,7 @@ const DECK_SIZE: u32 = 40;
 
 impl Card {
     pub fn from_id(card_id: u32) -> Result<Self, Error> {
-        if card_id >= DECK_SIZE {
+        if card_id >= SUIT_CARD_COUNT {
             return Err(Error::InvalidCard);
         }
         
@@ -126,6 +263,31 @@ impl Card {
     pub fn value(&self) -> u32 {
         self.rank
     }
+
+    /// Bit for this card's suit in a turn's suits mask
+    pub fn suit_bit(&self) -> u32 {
+        1 << self.suit
+    }
+}
+
+impl Ability {
+    pub fn from_card_id(card_id: u32) -> Option<Self> {
+        match card_id.checked_sub(SUIT_CARD_COUNT)? {
+            0 => Some(Ability::Hook),
+            1 => Some(Ability::Cannon),
+            2 => Some(Ability::Sword),
+            3 => Some(Ability::Map),
+            4 => Some(Ability::Oracle),
+            5 => Some(Ability::Anchor),
+            6 => Some(Ability::Key),
+            7 => Some(Ability::Chest),
+            _ => None,
+        }
+    }
+
+    pub fn to_card_id(self) -> u32 {
+        SUIT_CARD_COUNT + self as u32
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -149,25 +311,111 @@ impl DeadMansDrawContract {
         env.storage().instance().extend_ttl(GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
     }
 
-    /// Start a new game session with deck commitments.
+    /// Set the verification key for the draw circuit (admin only)
+    pub fn set_draw_vk(env: Env, vk: VerificationKey) {
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::DrawVerificationKey, &vk);
+    }
+
+    /// Set the bounds sessions' rules must fall within (admin only)
+    ///
+    /// Decks hold each card at most once, so `max_deck_size` can't exceed
+    /// FULL_DECK_SIZE.
+    pub fn set_rule_bounds(env: Env, bounds: GameRuleBounds) {
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+
+        if bounds.min_win_score == 0
+            || bounds.max_win_score < bounds.min_win_score
+            || bounds.max_busts == 0
+            || bounds.max_deck_size == 0
+            || bounds.max_deck_size > FULL_DECK_SIZE
+        {
+            panic_with_error!(&env, Error::InvalidRules);
+        }
+
+        env.storage().instance().set(&DataKey::GameRuleBounds, &bounds);
+    }
+
+    /// Get the bounds on per-session rules
+    pub fn get_rule_bounds(env: Env) -> GameRuleBounds {
+        env.storage().instance()
+            .get(&DataKey::GameRuleBounds)
+            .unwrap_or(GameRuleBounds {
+                min_win_score: DEFAULT_MIN_WIN_SCORE,
+                max_win_score: DEFAULT_MAX_WIN_SCORE,
+                max_busts: DEFAULT_MAX_BUST_LIMIT,
+                max_deck_size: DEFAULT_MAX_DECK_SIZE,
+            })
+    }
+
+    /// Start a new game session with deck commitments and each player's stake.
+    ///
+    /// `rules` defaults to a 60-point, three-bust game over 48-card decks.
     pub fn start_game(
         env: Env,
         session_id: u32,
         player1: Address,
         player2: Address,
+        p1_points: i128,
+        p2_points: i128,
         p1_deck_root: Bytes,
         p2_deck_root: Bytes,
+        rules: Option<GameRules>,
     ) {
         if !env.storage().instance().has(&DataKey::Initialized) {
             panic_with_error!(&env, Error::NotInitialized);
         }
+        Self::require_not_paused(&env);
 
         if player1 == player2 {
             panic_with_error!(&env, Error::InvalidMove);
         }
 
-        player1.require_auth();
-        player2.require_auth();
+        if p1_points < 0 || p2_points < 0 {
+            panic_with_error!(&env, Error::InvalidMove);
+        }
+
+        // Each player authorizes the session, the opponent, their own stake and
+        // deck, and the rules
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2.into_val(&env),
+            p1_points.into_val(&env),
+            p1_deck_root.into_val(&env),
+            rules.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1.into_val(&env),
+            p2_points.into_val(&env),
+            p2_deck_root.into_val(&env),
+            rules.into_val(&env),
+        ]);
+
+        let rules = rules.unwrap_or(GameRules {
+            win_score: DEFAULT_WIN_SCORE,
+            max_busts: DEFAULT_MAX_BUSTS,
+            deck_size: DEFAULT_DECK_SIZE,
+        });
+        let bounds = Self::get_rule_bounds(env.clone());
+        if rules.win_score < bounds.min_win_score
+            || rules.win_score > bounds.max_win_score
+            || rules.max_busts == 0
+            || rules.max_busts > bounds.max_busts
+            || rules.deck_size == 0
+            || rules.deck_size > bounds.max_deck_size
+        {
+            panic_with_error!(&env, Error::InvalidRules);
+        }
 
         let game_key = DataKey::GameState(session_id);
         if env.storage().temporary().has(&game_key) {
@@ -183,6 +431,9 @@ impl DeadMansDrawContract {
             session_id,
             player1: player1.clone(),
             player2: player2.clone(),
+            p1_points,
+            p2_points,
+            rules,
             p1_deck_root,
             p2_deck_root,
             p1_commit: None,
@@ -196,12 +447,21 @@ impl DeadMansDrawContract {
             p2_busts: 0,
             p1_cards_drawn: 0,
             p2_cards_drawn: 0,
+            p1_bank: Vec::new(&env),
+            p2_bank: Vec::new(&env),
+            discard: Vec::new(&env),
             active_player: player1.clone(),
             turn_cards: Vec::new(&env),
             turn_suits_mask: 0,
             turn_score: 0,
+            pending_ability: None,
+            turn_anchor: 0,
+            turn_oracle: false,
             phase: Phase::Commit,
             turn_number: 1,
+            last_action_ledger: env.ledger().sequence(),
+            draw_offer: None,
+            turns_logged: 0,
         };
 
         env.storage().temporary().set(&game_key, &state);
@@ -217,8 +477,8 @@ impl DeadMansDrawContract {
             &session_id,
             &player1,
             &player2,
-            &0i128,
-            &0i128
+            &p1_points,
+            &p2_points
         );
     }
 
@@ -242,6 +502,7 @@ impl DeadMansDrawContract {
         } else {
             panic_with_error!(&env, Error::NotPlayer);
         }
+        state.last_action_ledger = env.ledger().sequence();
 
         if state.p1_commit.is_some() && state.p2_commit.is_some() {
             state.phase = Phase::Reveal;
@@ -287,6 +548,8 @@ impl DeadMansDrawContract {
             panic_with_error!(&env, Error::NotPlayer);
         }
 
+        state.last_action_ledger = env.ledger().sequence();
+
         let mut current_seed = state.shared_seed;
         current_seed.append(&seed);
         state.shared_seed = current_seed;
@@ -302,7 +565,7 @@ impl DeadMansDrawContract {
             } else {
                 state.active_player = state.player2.clone();
             }
-            state.phase = Phase::Playing; 
+            state.phase = Phase::Playing;
             env.events().publish((symbol_short!("PHASE"), session_id), Phase::Playing);
         }
         
@@ -311,96 +574,166 @@ impl DeadMansDrawContract {
     }
 
     /// Draw a card with ZK proof
+    ///
+    /// Public inputs, each a 32-byte big-endian field element:
+    /// [0] = active player's deck root
+    /// [1] = card_id
+    /// [2] = is_bust (0 or 1)
+    /// [3] = new_suits_mask
+    ///
+    /// The bust flag and new mask are derived on-chain from the card's suit and the
+    /// turn's suits mask; the proof's inputs must agree with them. Ability cards
+    /// never bust and leave the mask unchanged.
+    /// `merkle_proof` shows the card sits at the player's next deck position.
     pub fn draw_card(
         env: Env,
         session_id: u32,
         card_id: u32,
-        proof: Bytes,
-        is_bust: bool,
-        new_suits_mask: u32,
+        proof: ZKProof,
+        merkle_proof: MerkleProof,
     ) {
         let game_key = DataKey::GameState(session_id);
-        let mut state: GameState = env.storage().temporary()
+        let state: GameState = env.storage().temporary()
             .get(&game_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
         
         state.active_player.require_auth();
+        Self::require_not_paused(&env);
         
         if state.phase != Phase::Playing {
             panic_with_error!(&env, Error::NotInPhase);
         }
+        if state.pending_ability.is_some() {
+            panic_with_error!(&env, Error::InvalidMove);
+        }
 
-        // Validate proof (stub - will integrate Protocol 25 verification)
-        if proof.len() == 0 {
-            panic_with_error!(&env, Error::InvalidProof);
+        let (is_bust, new_suits_mask) = if Ability::from_card_id(card_id).is_some() {
+            (false, state.turn_suits_mask)
+        } else {
+            let card = Card::from_id(card_id)
+                .unwrap_or_else(|_| panic_with_error!(&env, Error::InvalidCard));
+            (state.turn_suits_mask & card.suit_bit() != 0, state.turn_suits_mask | card.suit_bit())
+        };
+
+        // Verify the deck membership and proof before touching any state
+        if !Self::verify_deck_membership(&env, &state, card_id, &merkle_proof) {
+            panic_with_error!(&env, Error::InvalidMerkleProof);
+        }
+        if let Err(err) = Self::verify_draw_proof(&env, &state, card_id, is_bust, new_suits_mask, &proof) {
+            panic_with_error!(&env, err);
         }
 
-        // TODO: Verify ZK proof that:
-        // 1. Card exists in player's deck
-        // 2. Bust detection is correct
-        // 3. New suits mask is correct
+        Self::apply_draw(env, state, card_id);
+    }
 
-        let card = Card::from_id(card_id)
-            .unwrap_or_else(|_| panic_with_error!(&env, Error::InvalidCard));
+    /// Helper: Apply a verified draw to the active player's turn
+    ///
+    /// Drawing a suit already in this turn's mask is a bust. Ability cards wait
+    /// in the play area for `play_ability`.
+    fn apply_draw(env: Env, mut state: GameState, card_id: u32) {
+        state.last_action_ledger = env.ledger().sequence();
 
         // Update cards drawn counter
         if state.active_player == state.player1 {
             state.p1_cards_drawn += 1;
-            if state.p1_cards_drawn > DECK_SIZE {
+            if state.p1_cards_drawn > state.rules.deck_size {
                 panic_with_error!(&env, Error::InvalidMove);
             }
         } else {
             state.p2_cards_drawn += 1;
-            if state.p2_cards_drawn > DECK_SIZE {
+            if state.p2_cards_drawn > state.rules.deck_size {
                 panic_with_error!(&env, Error::InvalidMove);
             }
         }
 
-        if is_bust {
-            // BUST! Lose all cards this turn
-            state.turn_cards = Vec::new(&env);
-            state.turn_suits_mask = 0;
-            state.turn_score = 0;
-            
-            if state.active_player == state.player1 {
-                state.p1_busts += 1;
-            } else {
-                state.p2_busts += 1;
-            }
-            
-            env.events().publish(
-                (symbol_short!("BUST"), session_id),
-                state.active_player.clone()
-            );
-            
-            // Check if player has busted too many times
-            let busts = if state.active_player == state.player1 {
-                state.p1_busts
-            } else {
-                state.p2_busts
-            };
-            
-            if busts >= MAX_BUSTS {
+        if let Some(ability) = Ability::from_card_id(card_id) {
+            state.turn_cards.push_back(card_id);
+            state.pending_ability = Some(ability);
+            Self::publish_draw(&env, &state, card_id);
+            Self::save_game(&env, &state);
+            return;
+        }
+
+        let card = Card::from_id(card_id)
+            .unwrap_or_else(|_| panic_with_error!(&env, Error::InvalidCard));
+
+        if Self::add_to_turn(&mut state, &card) {
+            if Self::resolve_bust(&env, &mut state) {
                 Self::finalize_game(env.clone(), state.clone());
                 return;
             }
-            
-            // End turn automatically on bust
-            Self::switch_player(&mut state);
         } else {
-            // Safe draw - add to turn
-            state.turn_cards.push_back(card_id);
-            state.turn_suits_mask = new_suits_mask;
-            state.turn_score += card.value();
-            
-            env.events().publish(
-                (symbol_short!("DRAW"), session_id),
-                card_id as u32
-            );
+            Self::publish_draw(&env, &state, card_id);
         }
 
-        env.storage().temporary().set(&game_key, &state);
-        env.storage().temporary().extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::save_game(&env, &state);
+    }
+
+    /// Resolve the ability card drawn this turn
+    ///
+    /// `target` indexes the pile the ability acts on: your own bank for Hook,
+    /// the opponent's bank for Cannon and Sword, and the discard pile for Map.
+    /// Other abilities ignore it. An ability whose pile is empty fizzles.
+    pub fn play_ability(env: Env, session_id: u32, target: u32) {
+        let game_key = DataKey::GameState(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&game_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+
+        state.active_player.require_auth();
+        Self::require_not_paused(&env);
+
+        if state.phase != Phase::Playing {
+            panic_with_error!(&env, Error::NotInPhase);
+        }
+        let ability = state.pending_ability
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidMove));
+        state.pending_ability = None;
+        state.last_action_ledger = env.ledger().sequence();
+
+        let active_is_p1 = state.active_player == state.player1;
+        let mut busted = false;
+        match ability {
+            Ability::Hook => {
+                if let Some(card) = Self::take_banked(&env, &mut state, active_is_p1, target) {
+                    busted = Self::add_to_turn(&mut state, &card);
+                }
+            }
+            Ability::Sword => {
+                if let Some(card) = Self::take_banked(&env, &mut state, !active_is_p1, target) {
+                    busted = Self::add_to_turn(&mut state, &card);
+                }
+            }
+            Ability::Cannon => {
+                if let Some(card) = Self::take_banked(&env, &mut state, !active_is_p1, target) {
+                    state.discard.push_back(card.to_id());
+                }
+            }
+            Ability::Map => {
+                let mut discard = state.discard.clone();
+                if let Some(card) = Self::take_card(&env, &mut discard, target) {
+                    state.discard = discard;
+                    busted = Self::add_to_turn(&mut state, &card);
+                }
+            }
+            Ability::Oracle => state.turn_oracle = true,
+            // The Anchor is the last card in play; everything before it is protected
+            Ability::Anchor => state.turn_anchor = state.turn_cards.len() - 1,
+            // Key and Chest pay out when the turn is banked
+            Ability::Key | Ability::Chest => {}
+        }
+
+        env.events().publish(
+            (symbol_short!("ABILITY"), session_id),
+            (ability, target)
+        );
+
+        if busted && Self::resolve_bust(&env, &mut state) {
+            Self::finalize_game(env.clone(), state.clone());
+            return;
+        }
+
+        Self::save_game(&env, &state);
     }
 
     /// Bank cards (stop drawing and add to score)
@@ -411,30 +744,53 @@ impl DeadMansDrawContract {
             .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
         
         state.active_player.require_auth();
+        Self::require_not_paused(&env);
         
         if state.phase != Phase::Playing {
             panic_with_error!(&env, Error::NotInPhase);
         }
+        if state.pending_ability.is_some() {
+            panic_with_error!(&env, Error::InvalidMove);
+        }
+        state.last_action_ledger = env.ledger().sequence();
 
-        // Add turn score to player's total
+        // Key and Chest banked together double the turn
+        let mut banked = state.turn_score;
+        if state.turn_cards.contains(Ability::Key.to_card_id())
+            && state.turn_cards.contains(Ability::Chest.to_card_id())
+        {
+            banked *= 2;
+        }
+
+        // Move suit cards to the player's bank and add to their total
+        let cards = Self::suit_cards(&env, &state.turn_cards);
         if state.active_player == state.player1 {
-            state.p1_score += state.turn_score;
+            state.p1_bank.append(&cards);
+            state.p1_score += banked;
         } else {
-            state.p2_score += state.turn_score;
+            state.p2_bank.append(&cards);
+            state.p2_score += banked;
         }
 
+        // (player, turn, banked, p1_score, p2_score, p1_busts, p2_busts)
         env.events().publish(
             (symbol_short!("BANK"), session_id),
-            state.turn_score
+            (
+                state.active_player.clone(),
+                state.turn_number,
+                banked,
+                state.p1_score,
+                state.p2_score,
+                state.p1_busts,
+                state.p2_busts,
+            )
         );
 
-        // Clear turn state
-        state.turn_cards = Vec::new(&env);
-        state.turn_suits_mask = 0;
-        state.turn_score = 0;
+        Self::record_turn(&env, &mut state, TurnOutcome::Banked, banked);
+        Self::clear_turn(&env, &mut state);
 
         // Check win condition
-        if state.p1_score >= WIN_SCORE || state.p2_score >= WIN_SCORE {
+        if state.p1_score >= state.rules.win_score || state.p2_score >= state.rules.win_score {
             Self::finalize_game(env.clone(), state.clone());
             return;
         }
@@ -442,10 +798,405 @@ impl DeadMansDrawContract {
         // Switch to next player
         Self::switch_player(&mut state);
 
-        env.storage().temporary().set(&game_key, &state);
+        Self::save_game(&env, &state);
+    }
+
+    /// Helper: Publish a DRAW event for a card entering the active player's turn
+    ///
+    /// Payload: (player, turn, card_id, turn_score, p1_score, p2_score, p1_busts, p2_busts)
+    fn publish_draw(env: &Env, state: &GameState, card_id: u32) {
+        env.events().publish(
+            (symbol_short!("DRAW"), state.session_id),
+            (
+                state.active_player.clone(),
+                state.turn_number,
+                card_id,
+                state.turn_score,
+                state.p1_score,
+                state.p2_score,
+                state.p1_busts,
+                state.p2_busts,
+            )
+        );
+    }
+
+    /// Helper: Reject gameplay while the admin has paused the contract
+    fn require_not_paused(env: &Env) {
+        if env.storage().instance().get(&DataKey::Paused).unwrap_or(false) {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    /// Helper: Put a suit card into the active player's play area
+    ///
+    /// Returns true if the card busts the turn. An Oracle discards the card
+    /// instead; either way it only guards the one card.
+    fn add_to_turn(state: &mut GameState, card: &Card) -> bool {
+        let oracle = state.turn_oracle;
+        state.turn_oracle = false;
+
+        if state.turn_suits_mask & card.suit_bit() != 0 {
+            if oracle {
+                state.discard.push_back(card.to_id());
+                return false;
+            }
+            // The busting card goes down with the rest of the turn
+            state.turn_cards.push_back(card.to_id());
+            return true;
+        }
+
+        state.turn_cards.push_back(card.to_id());
+        state.turn_suits_mask |= card.suit_bit();
+        state.turn_score += card.value();
+        false
+    }
+
+    /// Helper: Resolve a bust for the active player
+    ///
+    /// Cards an Anchor protects are banked, the rest of the turn is discarded.
+    /// Returns true if the bust ends the game; otherwise the turn passes.
+    fn resolve_bust(env: &Env, state: &mut GameState) -> bool {
+        // add_to_turn leaves the busting card last in play
+        let bust_card = state.turn_cards.last().unwrap();
+        let mut saved = Vec::new(env);
+        let mut saved_score = 0;
+        for (i, card_id) in state.turn_cards.iter().enumerate() {
+            // Ability cards simply leave play
+            let Ok(card) = Card::from_id(card_id) else { continue };
+            if (i as u32) < state.turn_anchor {
+                saved.push_back(card_id);
+                saved_score += card.value();
+            } else {
+                state.discard.push_back(card_id);
+            }
+        }
+
+        let (busts, score) = if state.active_player == state.player1 {
+            state.p1_bank.append(&saved);
+            state.p1_score += saved_score;
+            state.p1_busts += 1;
+            (state.p1_busts, state.p1_score)
+        } else {
+            state.p2_bank.append(&saved);
+            state.p2_score += saved_score;
+            state.p2_busts += 1;
+            (state.p2_busts, state.p2_score)
+        };
+
+        // (player, turn, card_id, saved, p1_score, p2_score, p1_busts, p2_busts)
+        env.events().publish(
+            (symbol_short!("BUST"), state.session_id),
+            (
+                state.active_player.clone(),
+                state.turn_number,
+                bust_card,
+                saved_score,
+                state.p1_score,
+                state.p2_score,
+                state.p1_busts,
+                state.p2_busts,
+            )
+        );
+
+        Self::record_turn(env, state, TurnOutcome::Busted, saved_score);
+        Self::clear_turn(env, state);
+
+        // Check if player has busted too many times (or anchored their way to a win)
+        if busts >= state.rules.max_busts || score >= state.rules.win_score {
+            return true;
+        }
+
+        // End turn automatically on bust
+        Self::switch_player(state);
+        false
+    }
+
+    /// Helper: Remove a banked card, taking its value off that player's score
+    fn take_banked(env: &Env, state: &mut GameState, from_p1: bool, index: u32) -> Option<Card> {
+        if from_p1 {
+            let card = Self::take_card(env, &mut state.p1_bank, index)?;
+            state.p1_score -= card.value();
+            Some(card)
+        } else {
+            let card = Self::take_card(env, &mut state.p2_bank, index)?;
+            state.p2_score -= card.value();
+            Some(card)
+        }
+    }
+
+    /// Helper: Remove the card at `index` from a pile, or None if the pile is empty
+    fn take_card(env: &Env, pile: &mut Vec<u32>, index: u32) -> Option<Card> {
+        if pile.is_empty() {
+            return None;
+        }
+        let card_id = pile.get(index)
+            .unwrap_or_else(|| panic_with_error!(env, Error::InvalidMove));
+        pile.remove(index);
+        Some(Card::from_id(card_id).unwrap_or_else(|_| panic_with_error!(env, Error::InvalidCard)))
+    }
+
+    /// Helper: The suit cards among `card_ids`
+    fn suit_cards(env: &Env, card_ids: &Vec<u32>) -> Vec<u32> {
+        let mut cards = Vec::new(env);
+        for card_id in card_ids.iter() {
+            if card_id < SUIT_CARD_COUNT {
+                cards.push_back(card_id);
+            }
+        }
+        cards
+    }
+
+    /// Helper: Append the ending turn to the session's history
+    fn record_turn(env: &Env, state: &mut GameState, outcome: TurnOutcome, score_delta: u32) {
+        let record = TurnRecord {
+            turn_number: state.turn_number,
+            player: state.active_player.clone(),
+            cards: state.turn_cards.clone(),
+            outcome,
+            score_delta,
+        };
+
+        let turn_key = DataKey::Turn(state.session_id, state.turns_logged);
+        env.storage().temporary().set(&turn_key, &record);
+        env.storage().temporary().extend_ttl(&turn_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        state.turns_logged += 1;
+    }
+
+    /// Helper: Reset the play area for the next turn
+    fn clear_turn(env: &Env, state: &mut GameState) {
+        state.turn_cards = Vec::new(env);
+        state.turn_suits_mask = 0;
+        state.turn_score = 0;
+        state.pending_ability = None;
+        state.turn_anchor = 0;
+        state.turn_oracle = false;
+    }
+
+    /// Helper: Persist game state and extend its TTL
+    fn save_game(env: &Env, state: &GameState) {
+        let game_key = DataKey::GameState(state.session_id);
+        env.storage().temporary().set(&game_key, state);
         env.storage().temporary().extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
     }
 
+    /// Helper: Check the draw proof's public inputs against the claimed draw and verify it
+    fn verify_draw_proof(
+        env: &Env,
+        state: &GameState,
+        card_id: u32,
+        is_bust: bool,
+        new_suits_mask: u32,
+        proof: &ZKProof,
+    ) -> Result<(), Error> {
+        if proof.public_inputs.len() < 4 {
+            return Err(Error::InvalidProofFormat);
+        }
+
+        let deck_root = if state.active_player == state.player1 {
+            &state.p1_deck_root
+        } else {
+            &state.p2_deck_root
+        };
+        let proven_root: Bytes = proof.public_inputs.get(0).unwrap().into();
+        if &proven_root != deck_root
+            || proof.public_inputs.get(1).unwrap() != Self::u32_signal(env, card_id)
+            || proof.public_inputs.get(2).unwrap() != Self::u32_signal(env, is_bust as u32)
+        {
+            return Err(Error::InvalidProof);
+        }
+
+        // The mask may only gain the drawn card's suit bit; clearing or adding
+        // any other bit is an illegal transition
+        if proof.public_inputs.get(3).unwrap() != Self::u32_signal(env, new_suits_mask) {
+            return Err(Error::InvalidMove);
+        }
+
+        let vk: VerificationKey = env.storage().instance()
+            .get(&DataKey::DrawVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
+
+        let parsed_vk = parse_verification_key(env, &vk)?;
+        let parsed_proof = parse_proof(env, &proof.proof)?;
+        let pub_signals = parse_public_signals(env, &proof.public_inputs);
+        if !verify_groth16_proof(env, parsed_vk, parsed_proof, pub_signals)? {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Helper: Check that `card_id` is the next card in the active player's committed deck
+    fn verify_deck_membership(env: &Env, state: &GameState, card_id: u32, merkle_proof: &MerkleProof) -> bool {
+        let (deck_root, cards_drawn) = if state.active_player == state.player1 {
+            (&state.p1_deck_root, state.p1_cards_drawn)
+        } else {
+            (&state.p2_deck_root, state.p2_cards_drawn)
+        };
+        // Cards are drawn in deck order, so each leaf can only be drawn once
+        if merkle_proof.index != cards_drawn {
+            return false;
+        }
+
+        let mut leaf = Bytes::from_array(env, &merkle_proof.index.to_be_bytes());
+        leaf.extend_from_array(&card_id.to_be_bytes());
+        let mut node: BytesN<32> = env.crypto().sha256(&leaf).into();
+
+        let mut index = merkle_proof.index;
+        for sibling in merkle_proof.siblings.iter() {
+            let mut pair = Bytes::new(env);
+            if index % 2 == 0 {
+                pair.append(&node.into());
+                pair.append(&sibling.into());
+            } else {
+                pair.append(&sibling.into());
+                pair.append(&node.into());
+            }
+            node = env.crypto().sha256(&pair).into();
+            index /= 2;
+        }
+
+        let root: Bytes = node.into();
+        &root == deck_root
+    }
+
+    /// Helper: Encode a u32 as a 32-byte big-endian public input
+    fn u32_signal(env: &Env, value: u32) -> BytesN<32> {
+        let mut bytes = [0u8; 32];
+        bytes[28..].copy_from_slice(&value.to_be_bytes());
+        BytesN::from_array(env, &bytes)
+    }
+
+    /// Claim the win when the opponent has stalled for TURN_TIMEOUT_LEDGERS
+    ///
+    /// While playing, the waiting player can claim against the active player.
+    /// During Commit and Reveal, a player who has committed (or revealed) can
+    /// claim against one who hasn't.
+    pub fn claim_timeout(env: Env, session_id: u32) {
+        let game_key = DataKey::GameState(session_id);
+        let state: GameState = env.storage().temporary()
+            .get(&game_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+
+        // Only the player who isn't holding the game up can claim
+        let claimant = match state.phase {
+            Phase::Commit => Self::setup_claimant(
+                &env,
+                &state,
+                state.p1_commit.is_some(),
+                state.p2_commit.is_some(),
+            ),
+            Phase::Reveal => Self::setup_claimant(&env, &state, state.p1_revealed, state.p2_revealed),
+            Phase::Playing => {
+                if state.active_player == state.player1 {
+                    state.player2.clone()
+                } else {
+                    state.player1.clone()
+                }
+            }
+            _ => panic_with_error!(&env, Error::NotInPhase),
+        };
+        claimant.require_auth();
+
+        if env.ledger().sequence() < state.last_action_ledger + TURN_TIMEOUT_LEDGERS {
+            panic_with_error!(&env, Error::TimeoutNotReached);
+        }
+
+        let p1_won = claimant == state.player1;
+        Self::settle_game(env, state, p1_won);
+    }
+
+    /// Helper: The player who has finished the current setup phase while the other hasn't
+    ///
+    /// Panics with InvalidMove when both or neither have, as nobody is stalling
+    /// the other.
+    fn setup_claimant(env: &Env, state: &GameState, p1_done: bool, p2_done: bool) -> Address {
+        match (p1_done, p2_done) {
+            (true, false) => state.player1.clone(),
+            (false, true) => state.player2.clone(),
+            _ => panic_with_error!(env, Error::InvalidMove),
+        }
+    }
+
+    /// Concede the game to the opponent
+    pub fn surrender(env: Env, session_id: u32, player: Address) {
+        player.require_auth();
+
+        let game_key = DataKey::GameState(session_id);
+        let state: GameState = env.storage().temporary()
+            .get(&game_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+
+        if state.phase == Phase::Finished || state.phase == Phase::Tied {
+            panic_with_error!(&env, Error::NotInPhase);
+        }
+
+        let p1_won = if player == state.player1 {
+            false
+        } else if player == state.player2 {
+            true
+        } else {
+            panic_with_error!(&env, Error::NotPlayer);
+        };
+
+        env.events().publish((symbol_short!("SURRENDER"), session_id), player);
+        Self::settle_game(env, state, p1_won);
+    }
+
+    /// Offer the opponent a tie; the offer lapses when the turn passes
+    pub fn offer_draw(env: Env, session_id: u32, player: Address) {
+        player.require_auth();
+
+        let game_key = DataKey::GameState(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&game_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+
+        if state.phase != Phase::Playing {
+            panic_with_error!(&env, Error::NotInPhase);
+        }
+        if player != state.player1 && player != state.player2 {
+            panic_with_error!(&env, Error::NotPlayer);
+        }
+
+        state.draw_offer = Some(player.clone());
+        env.events().publish((symbol_short!("TIE_OFFER"), session_id), player);
+
+        Self::save_game(&env, &state);
+    }
+
+    /// Accept the opponent's tie offer, ending the game as `Phase::Tied`
+    ///
+    /// Game Hub has no draw outcome, so like a chess draw the tie is not
+    /// reported with `end_game`: neither player's points are paid out.
+    pub fn accept_draw(env: Env, session_id: u32, player: Address) {
+        player.require_auth();
+
+        let game_key = DataKey::GameState(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&game_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+
+        if state.phase != Phase::Playing {
+            panic_with_error!(&env, Error::NotInPhase);
+        }
+        if player != state.player1 && player != state.player2 {
+            panic_with_error!(&env, Error::NotPlayer);
+        }
+
+        // Only the opponent of the offering player can accept
+        match &state.draw_offer {
+            Some(offered_by) if *offered_by != player => {}
+            _ => panic_with_error!(&env, Error::InvalidMove),
+        }
+
+        state.phase = Phase::Tied;
+        state.draw_offer = None;
+
+        env.events().publish((symbol_short!("TIE"), session_id), player);
+
+        Self::save_game(&env, &state);
+    }
+
     /// Helper: Switch active player
     fn switch_player(state: &mut GameState) {
         if state.active_player == state.player1 {
@@ -454,35 +1205,50 @@ impl DeadMansDrawContract {
             state.active_player = state.player1.clone();
         }
         state.turn_number += 1;
+        state.draw_offer = None;
     }
 
     /// Finalize game and notify Game Hub
-    fn finalize_game(env: Env, mut state: GameState) {
-        state.phase = Phase::Finished;
-        
-        let game_hub_addr: Address = env.storage().instance()
-            .get(&DataKey::GameHub)
-            .unwrap();
-        let client = GameHubClient::new(&env, &game_hub_addr);
-        
+    fn finalize_game(env: Env, state: GameState) {
         // Determine winner
-        let p1_won = if state.p1_score >= WIN_SCORE {
+        let rules = &state.rules;
+        let p1_won = if state.p1_score >= rules.win_score {
             true
-        } else if state.p2_score >= WIN_SCORE {
+        } else if state.p2_score >= rules.win_score {
             false
-        } else if state.p2_busts >= MAX_BUSTS {
+        } else if state.p2_busts >= rules.max_busts {
             true
-        } else if state.p1_busts >= MAX_BUSTS {
+        } else if state.p1_busts >= rules.max_busts {
             false
         } else {
             state.p1_score > state.p2_score
         };
+
+        Self::settle_game(env, state, p1_won);
+    }
+
+    /// Helper: Finish the game with the given winner and notify Game Hub
+    fn settle_game(env: Env, mut state: GameState, p1_won: bool) {
+        state.phase = Phase::Finished;
+        
+        let game_hub_addr: Address = env.storage().instance()
+            .get(&DataKey::GameHub)
+            .unwrap();
+        let client = GameHubClient::new(&env, &game_hub_addr);
         
         client.end_game(&state.session_id, &p1_won);
         
+        // (winner, turn, p1_score, p2_score, p1_busts, p2_busts)
         env.events().publish(
             (symbol_short!("WINNER"), state.session_id),
-            if p1_won { state.player1.clone() } else { state.player2.clone() }
+            (
+                if p1_won { state.player1.clone() } else { state.player2.clone() },
+                state.turn_number,
+                state.p1_score,
+                state.p2_score,
+                state.p1_busts,
+                state.p2_busts,
+            )
         );
         
         let game_key = DataKey::GameState(state.session_id);
@@ -490,6 +1256,81 @@ impl DeadMansDrawContract {
         env.storage().temporary().extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
     }
     
+    /// Get the current admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized))
+    }
+
+    /// Set a new admin address (admin only)
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage().instance()
+            .get(&DataKey::GameHub)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized))
+    }
+
+    /// Set a new GameHub contract address (admin only)
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::GameHub, &new_hub);
+    }
+
+    /// Halt gameplay entrypoints (admin only)
+    pub fn pause(env: Env) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &true);
+    }
+
+    /// Resume gameplay (admin only)
+    pub fn unpause(env: Env) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &false);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+    }
+
+    /// Update the contract WASM hash (admin only)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Get up to `limit` finished turns starting at history index `start`
+    ///
+    /// Pages are capped at MAX_TURNS_PAGE entries.
+    pub fn get_turns(env: Env, session_id: u32, start: u32, limit: u32) -> Vec<TurnRecord> {
+        let state = Self::get_game(env.clone(), session_id);
+        let end = start.saturating_add(limit.min(MAX_TURNS_PAGE)).min(state.turns_logged);
+
+        let mut turns = Vec::new(&env);
+        for index in start..end {
+            let record: TurnRecord = env.storage().temporary()
+                .get(&DataKey::Turn(session_id, index))
+                .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotFound));
+            turns.push_back(record);
+        }
+        turns
+    }
+
     /// Get current game state
     pub fn get_game(env: Env, session_id: u32) -> GameState {
         let game_key = DataKey::GameState(session_id);
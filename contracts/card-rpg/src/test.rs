@@ -1,9 +1,12 @@
 #![cfg(test)]
 
-use crate::{DeadMansDrawContract, DeadMansDrawContractClient, Phase, Card};
+use crate::{
+    DeadMansDrawContract, DeadMansDrawContractClient, Phase, Card, Ability, DataKey, Error, GameState,
+    GameRuleBounds, GameRules, Groth16Proof, MerkleProof, TurnOutcome, ZKProof,
+};
 use soroban_sdk::{
-    contract, contractimpl, Address, Bytes, Env,
-    testutils::{Address as _, Ledger as _}
+    contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger as _}
 };
 
 #[contract]
@@ -22,7 +25,8 @@ impl MockGameHub {
     ) {
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.events().publish((symbol_short!("ENDED"), session_id), player1_won);
     }
 }
 
@@ -42,6 +46,58 @@ fn setup_test() -> (Env, DeadMansDrawContractClient<'static>, Address, Address,
     (env, client, admin, p1, p2)
 }
 
+/// Commit and reveal both players' seeds, moving the session into Playing
+fn reveal_seeds(env: &Env, client: &DeadMansDrawContractClient, session_id: u32, p1: &Address, p2: &Address) {
+    let seed1_raw = Bytes::from_slice(env, &[1u8; 32]);
+    let seed1_hash: Bytes = env.crypto().sha256(&seed1_raw).into();
+    let seed2_raw = Bytes::from_slice(env, &[2u8; 32]);
+    let seed2_hash: Bytes = env.crypto().sha256(&seed2_raw).into();
+
+    client.commit(&session_id, p1, &seed1_hash);
+    client.commit(&session_id, p2, &seed2_hash);
+    client.reveal(&session_id, p1, &seed1_raw);
+    client.reveal(&session_id, p2, &seed2_raw);
+}
+
+/// Start a session with placeholder deck roots and bring it to the Playing phase
+fn setup_playing(env: &Env, client: &DeadMansDrawContractClient, session_id: u32, p1: &Address, p2: &Address) {
+    let p1_deck_root = Bytes::from_slice(env, &[1u8; 32]);
+    let p2_deck_root = Bytes::from_slice(env, &[2u8; 32]);
+    client.start_game(&session_id, p1, p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+    reveal_seeds(env, client, session_id, p1, p2);
+}
+
+/// Apply a draw as if its proof had verified (real proofs need the trusted setup)
+fn draw(env: &Env, client: &DeadMansDrawContractClient, session_id: u32, card_id: u32) {
+    env.as_contract(&client.address, || {
+        let state: GameState = env.storage().temporary()
+            .get(&DataKey::GameState(session_id))
+            .unwrap();
+        DeadMansDrawContract::apply_draw(env.clone(), state, card_id);
+    });
+}
+
+fn score_of(state: &GameState, player: &Address) -> u32 {
+    if *player == state.player1 { state.p1_score } else { state.p2_score }
+}
+
+fn signal(env: &Env, value: u32) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[28..].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+fn mock_proof(env: &Env, public_inputs: soroban_sdk::Vec<BytesN<32>>) -> ZKProof {
+    ZKProof {
+        proof: Groth16Proof {
+            pi_a: BytesN::from_array(env, &[1u8; 64]),
+            pi_b: BytesN::from_array(env, &[2u8; 128]),
+            pi_c: BytesN::from_array(env, &[3u8; 64]),
+        },
+        public_inputs,
+    }
+}
+
 #[test]
 fn test_card_encoding() {
     // Test Card struct encoding/decoding
@@ -78,6 +134,14 @@ fn test_card_encoding() {
     assert_eq!(card.rank, 10);
     assert_eq!(card.to_id(), 39);
     assert_eq!(card.value(), 10);
+
+    // Ability cards follow the 40 suit cards
+    assert_eq!(Ability::from_card_id(39), None);
+    assert_eq!(Ability::from_card_id(40), Some(Ability::Hook));
+    assert_eq!(Ability::from_card_id(47), Some(Ability::Chest));
+    assert_eq!(Ability::from_card_id(48), None);
+    assert_eq!(Ability::Anchor.to_card_id(), 45);
+    assert!(Card::from_id(40).is_err());
 }
 
 #[test]
@@ -88,7 +152,22 @@ fn test_game_initialization() {
     let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
     let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
 
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+
+    // Each player signs over the opponent and their own deck as well as their stake
+    let auths = env.auths();
+    for (player, opponent, deck_root) in [(&p1, &p2, &p1_deck_root), (&p2, &p1, &p2_deck_root)] {
+        let expected = AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                client.address.clone(),
+                Symbol::new(&env, "start_game"),
+                (session_id, opponent.clone(), 100i128, deck_root.clone(), None::<GameRules>)
+                    .into_val(&env),
+            )),
+            sub_invocations: std::vec![],
+        };
+        assert!(auths.contains(&(player.clone(), expected)));
+    }
     
     let state = client.get_game(&session_id);
     assert_eq!(state.phase, Phase::Commit);
@@ -97,6 +176,12 @@ fn test_game_initialization() {
     assert_eq!(state.p2_score, 0);
     assert_eq!(state.p1_busts, 0);
     assert_eq!(state.p2_busts, 0);
+    assert_eq!(state.p1_points, 100);
+    assert_eq!(state.p2_points, 100);
+
+    // Stakes can't be negative
+    let result = client.try_start_game(&(session_id + 1), &p1, &p2, &-1, &100, &p1_deck_root, &p2_deck_root, &None);
+    assert!(result.is_err());
 }
 
 #[test]
@@ -107,7 +192,7 @@ fn test_commit_reveal_flow() {
     let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
     let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
 
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
     
     // Commit seeds
     let seed1_raw = Bytes::from_slice(&env, &[1u8; 32]);
@@ -137,29 +222,12 @@ fn test_draw_and_bank() {
     let (env, client, _admin, p1, p2) = setup_test();
 
     let session_id = 12345u32;
-    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
-    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+    setup_playing(&env, &client, session_id, &p1, &p2);
 
-    // Setup to Playing phase
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
-    
-    let seed1_raw = Bytes::from_slice(&env, &[1u8; 32]);
-    let seed1_hash: Bytes = env.crypto().sha256(&seed1_raw).into();
-    let seed2_raw = Bytes::from_slice(&env, &[2u8; 32]);
-    let seed2_hash: Bytes = env.crypto().sha256(&seed2_raw).into();
-    
-    client.commit(&session_id, &p1, &seed1_hash);
-    client.commit(&session_id, &p2, &seed2_hash);
-    client.reveal(&session_id, &p1, &seed1_raw);
-    client.reveal(&session_id, &p2, &seed2_raw);
-    
     // Draw card 5 (Swords 6, value=6)
-    let mock_proof = Bytes::from_slice(&env, &[0xAB; 64]);
     let card_id = 5u32;  // Swords 6
-    let is_bust = false;
-    let new_suits_mask = 0b0001u32;  // Swords bit set
     
-    client.draw_card(&session_id, &card_id, &mock_proof, &is_bust, &new_suits_mask);
+    draw(&env, &client, session_id, card_id);
     
     let state = client.get_game(&session_id);
     assert_eq!(state.turn_score, 6);
@@ -167,9 +235,8 @@ fn test_draw_and_bank() {
     
     // Draw card 18 (Coins 9, value=9)
     let card_id2 = 18u32;  // Coins 9
-    let new_suits_mask2 = 0b0011u32;  // Swords + Coins
     
-    client.draw_card(&session_id, &card_id2, &mock_proof, &is_bust, &new_suits_mask2);
+    draw(&env, &client, session_id, card_id2);
     
     let state = client.get_game(&session_id);
     assert_eq!(state.turn_score, 15);  // 6 + 9
@@ -198,33 +265,17 @@ fn test_bust_detection() {
     let (env, client, _admin, p1, p2) = setup_test();
 
     let session_id = 12345u32;
-    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
-    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+    setup_playing(&env, &client, session_id, &p1, &p2);
 
-    // Setup to Playing phase
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
-    
-    let seed1_raw = Bytes::from_slice(&env, &[1u8; 32]);
-    let seed1_hash: Bytes = env.crypto().sha256(&seed1_raw).into();
-    let seed2_raw = Bytes::from_slice(&env, &[2u8; 32]);
-    let seed2_hash: Bytes = env.crypto().sha256(&seed2_raw).into();
-    
-    client.commit(&session_id, &p1, &seed1_hash);
-    client.commit(&session_id, &p2, &seed2_hash);
-    client.reveal(&session_id, &p1, &seed1_raw);
-    client.reveal(&session_id, &p2, &seed2_raw);
-    
-    let mock_proof = Bytes::from_slice(&env, &[0xAB; 64]);
-    
     // Draw card 5 (Swords 6)
-    client.draw_card(&session_id, &5u32, &mock_proof, &false, &0b0001u32);
+    draw(&env, &client, session_id, 5u32);
     
     let state = client.get_game(&session_id);
     let initial_player = state.active_player.clone();
     assert_eq!(state.turn_score, 6);
     
     // Draw card 7 (Swords 8) - BUST! (duplicate suit)
-    client.draw_card(&session_id, &7u32, &mock_proof, &true, &0b0001u32);
+    draw(&env, &client, session_id, 7u32);
     
     let state = client.get_game(&session_id);
     
@@ -248,42 +299,28 @@ fn test_win_by_score() {
     let (env, client, _admin, p1, p2) = setup_test();
 
     let session_id = 12345u32;
-    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
-    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+    setup_playing(&env, &client, session_id, &p1, &p2);
 
-    // Setup to Playing phase
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
-    
-    let seed1_raw = Bytes::from_slice(&env, &[1u8; 32]);
-    let seed1_hash: Bytes = env.crypto().sha256(&seed1_raw).into();
-    let seed2_raw = Bytes::from_slice(&env, &[2u8; 32]);
-    let seed2_hash: Bytes = env.crypto().sha256(&seed2_raw).into();
-    
-    client.commit(&session_id, &p1, &seed1_hash);
-    client.commit(&session_id, &p2, &seed2_hash);
-    client.reveal(&session_id, &p1, &seed1_raw);
-    client.reveal(&session_id, &p2, &seed2_raw);
-    
-    let mock_proof = Bytes::from_slice(&env, &[0xAB; 64]);
-    
-    // Draw exactly 60 points worth of cards
+    // One card per suit is the most a turn can hold without busting
     // Card 9 = Swords rank 10 = 10 points
     // Card 19 = Coins rank 10 = 10 points  
     // Card 29 = Cups rank 10 = 10 points
     // Card 39 = Wands rank 10 = 10 points
-    // Card 8 = Swords rank 9 = 9 points
-    // Card 18 = Coins rank 9 = 9 points
-    // Card 1 = Swords rank 2 = 2 points
-    // Total = 10+10+10+10+9+9+2 = 60 points
-    let cards_to_draw = [9u32, 19u32, 29u32, 39u32, 8u32, 18u32, 1u32];
-    
-    for (i, card_id) in cards_to_draw.iter().enumerate() {
-        let mask = 1u32 << (i % 4);  // Different suits
-        client.draw_card(&session_id, card_id, &mock_proof, &false, &mask);
+    for card_id in [9u32, 19u32, 29u32, 39u32] {
+        draw(&env, &client, session_id, card_id);
     }
     
     let state = client.get_game(&session_id);
-    assert_eq!(state.turn_score, 60);
+    assert_eq!(state.turn_score, 40);
+    client.bank_cards(&session_id);
+
+    // Opponent passes without drawing
+    client.bank_cards(&session_id);
+
+    // Card 8 = Swords 9, Card 18 = Coins 9, Card 28 = Cups 9: 40 + 27 = 67 points
+    for card_id in [8u32, 18u32, 28u32] {
+        draw(&env, &client, session_id, card_id);
+    }
     
     // Bank to trigger win
     client.bank_cards(&session_id);
@@ -301,7 +338,7 @@ fn test_prevent_self_play() {
     let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
 
     // Try to start game with same player - should fail with Error::InvalidMove (code 9)
-    let result = client.try_start_game(&session_id, &p1, &p1, &p1_deck_root, &p2_deck_root);
+    let result = client.try_start_game(&session_id, &p1, &p1, &100, &100, &p1_deck_root, &p2_deck_root, &None);
     assert!(result.is_err());
 }
 
@@ -313,10 +350,460 @@ fn test_phase_validation() {
     let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
     let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
 
-    client.start_game(&session_id, &p1, &p2, &p1_deck_root, &p2_deck_root);
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
     
     // Try to draw before commit/reveal - should fail with Error::NotInPhase (code 3)
-    let mock_proof = Bytes::from_slice(&env, &[0xAB; 64]);
-    let result = client.try_draw_card(&session_id, &5u32, &mock_proof, &false, &0b0001u32);
+    let merkle = MerkleProof { index: 0, siblings: vec![&env] };
+    let result = client.try_draw_card(&session_id, &5u32, &mock_proof(&env, vec![&env]), &merkle);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_draw_proof_inputs() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    // Both decks are a single-card tree holding card 5 at position 0
+    let session_id = 12345u32;
+    let mut leaf = Bytes::from_array(&env, &0u32.to_be_bytes());
+    leaf.extend_from_array(&5u32.to_be_bytes());
+    let root: BytesN<32> = env.crypto().sha256(&leaf).into();
+    let p1_deck_root: Bytes = root.clone().into();
+    let p2_deck_root: Bytes = root.clone().into();
+
+    // Setup to Playing phase
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+    
+    reveal_seeds(&env, &client, session_id, &p1, &p2);
+
+    let merkle = MerkleProof { index: 0, siblings: vec![&env] };
+    let matching = mock_proof(&env, vec![&env, root.clone(), signal(&env, 5), signal(&env, 0), signal(&env, 1)]);
+
+    // A card that isn't at the next deck position
+    let result = client.try_draw_card(&session_id, &6u32, &matching, &merkle);
+    assert_eq!(result, Err(Ok(Error::InvalidMerkleProof)));
+    let skipped = MerkleProof { index: 1, siblings: vec![&env] };
+    let result = client.try_draw_card(&session_id, &5u32, &matching, &skipped);
+    assert_eq!(result, Err(Ok(Error::InvalidMerkleProof)));
+
+    // Inputs for a different card than the one claimed
+    let wrong = mock_proof(&env, vec![&env, root.clone(), signal(&env, 6), signal(&env, 0), signal(&env, 1)]);
+    let result = client.try_draw_card(&session_id, &5u32, &wrong, &merkle);
+    assert_eq!(result, Err(Ok(Error::InvalidProof)));
+
+    // A mask that drops or adds suits other than the drawn one
+    let cleared = mock_proof(&env, vec![&env, root.clone(), signal(&env, 5), signal(&env, 0), signal(&env, 0)]);
+    let result = client.try_draw_card(&session_id, &5u32, &cleared, &merkle);
+    assert_eq!(result, Err(Ok(Error::InvalidMove)));
+    let extra = mock_proof(&env, vec![&env, root, signal(&env, 5), signal(&env, 0), signal(&env, 0b0011)]);
+    let result = client.try_draw_card(&session_id, &5u32, &extra, &merkle);
+    assert_eq!(result, Err(Ok(Error::InvalidMove)));
+
+    // Matching inputs still need the admin-installed verification key
+    let result = client.try_draw_card(&session_id, &5u32, &matching, &merkle);
+    assert_eq!(result, Err(Ok(Error::VerificationKeyNotSet)));
+}
+
+#[test]
+fn test_draw_proof_mask_carries_over_within_turn() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    // Both decks are a two-card tree: Swords 6 (card 5) then Coins 9 (card 18)
+    let session_id = 12345u32;
+    let leaf_hash = |index: u32, card_id: u32| -> BytesN<32> {
+        let mut leaf = Bytes::from_array(&env, &index.to_be_bytes());
+        leaf.extend_from_array(&card_id.to_be_bytes());
+        env.crypto().sha256(&leaf).into()
+    };
+    let first = leaf_hash(0, 5);
+    let second = leaf_hash(1, 18);
+    let mut pair: Bytes = first.clone().into();
+    pair.append(&second.into());
+    let root: BytesN<32> = env.crypto().sha256(&pair).into();
+    let deck_root: Bytes = root.clone().into();
+
+    client.start_game(&session_id, &p1, &p2, &100, &100, &deck_root, &deck_root, &None);
+    reveal_seeds(&env, &client, session_id, &p1, &p2);
+
+    // The first draw leaves Swords in the turn's mask
+    draw(&env, &client, session_id, 5);
+    assert_eq!(client.get_game(&session_id).turn_suits_mask, 0b0001);
+
+    // Proving Coins on its own drops the Swords bit already drawn this turn
+    let merkle = MerkleProof { index: 1, siblings: vec![&env, first] };
+    let dropped = mock_proof(&env, vec![&env, root.clone(), signal(&env, 18), signal(&env, 0), signal(&env, 0b0010)]);
+    let result = client.try_draw_card(&session_id, &18u32, &dropped, &merkle);
+    assert_eq!(result, Err(Ok(Error::InvalidMove)));
+
+    // The carried-over mask passes the input checks and reaches verification
+    let carried = mock_proof(&env, vec![&env, root, signal(&env, 18), signal(&env, 0), signal(&env, 0b0011)]);
+    let result = client.try_draw_card(&session_id, &18u32, &carried, &merkle);
+    assert_eq!(result, Err(Ok(Error::VerificationKeyNotSet)));
+}
+
+#[test]
+fn test_claim_timeout() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    let result = client.try_claim_timeout(&session_id);
+    assert_eq!(result, Err(Ok(Error::TimeoutNotReached)));
+
+    env.ledger().with_mut(|li| li.sequence_number += 720);
+    client.claim_timeout(&session_id);
+
+    let state = client.get_game(&session_id);
+    assert_eq!(state.phase, Phase::Finished);
+}
+
+#[test]
+fn test_claim_timeout_during_setup() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
+    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+    let seed1_raw = Bytes::from_slice(&env, &[1u8; 32]);
+    let seed1_hash: Bytes = env.crypto().sha256(&seed1_raw).into();
+    let seed2_raw = Bytes::from_slice(&env, &[2u8; 32]);
+    let seed2_hash: Bytes = env.crypto().sha256(&seed2_raw).into();
+
+    // Nobody has committed, so nobody is stalling
+    client.start_game(&1, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+    env.ledger().with_mut(|li| li.sequence_number += 720);
+    assert_eq!(client.try_claim_timeout(&1), Err(Ok(Error::InvalidMove)));
+
+    // Player 2 never commits; the clock runs from player 1's commit
+    client.commit(&1, &p1, &seed1_hash);
+    assert_eq!(client.try_claim_timeout(&1), Err(Ok(Error::TimeoutNotReached)));
+    env.ledger().with_mut(|li| li.sequence_number += 720);
+    client.claim_timeout(&1);
+    assert_eq!(client.get_game(&1).phase, Phase::Finished);
+    assert_eq!(env.auths()[0].0, p1);
+
+    // Player 1 never reveals
+    client.start_game(&2, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+    client.commit(&2, &p1, &seed1_hash);
+    client.commit(&2, &p2, &seed2_hash);
+    client.reveal(&2, &p2, &seed2_raw);
+    assert_eq!(client.try_claim_timeout(&2), Err(Ok(Error::TimeoutNotReached)));
+    env.ledger().with_mut(|li| li.sequence_number += 720);
+    client.claim_timeout(&2);
+    assert_eq!(client.get_game(&2).phase, Phase::Finished);
+    assert_eq!(env.auths()[0].0, p2);
+}
+
+#[test]
+fn test_ability_cards() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    let first = client.get_game(&session_id).active_player;
+    let second = if first == p1 { p2.clone() } else { p1.clone() };
+
+    // First player banks Swords 10 and Coins 10
+    draw(&env, &client, session_id, 9);
+    draw(&env, &client, session_id, 19);
+    client.bank_cards(&session_id);
+    assert_eq!(score_of(&client.get_game(&session_id), &first), 20);
+
+    // Cannon must be resolved before drawing or banking again
+    draw(&env, &client, session_id, Ability::Cannon.to_card_id());
+    assert_eq!(client.try_bank_cards(&session_id), Err(Ok(Error::InvalidMove)));
+    client.play_ability(&session_id, &0);
+    let state = client.get_game(&session_id);
+    assert_eq!(score_of(&state, &first), 10);
+    assert_eq!(state.discard, vec![&env, 9u32]);
+    assert_eq!(client.try_play_ability(&session_id, &0), Err(Ok(Error::InvalidMove)));
+
+    // Anchor protects Swords 6; drawing Swords 8 busts but banks it anyway
+    draw(&env, &client, session_id, 5);
+    draw(&env, &client, session_id, Ability::Anchor.to_card_id());
+    client.play_ability(&session_id, &0);
+    draw(&env, &client, session_id, 7);
+    let state = client.get_game(&session_id);
+    assert_eq!(score_of(&state, &second), 6);
+    assert_eq!(state.active_player, first);
+    assert_eq!(state.discard, vec![&env, 9u32, 7u32]);
+
+    // Map recovers Swords 10, then Sword steals Swords 6 and busts on the suit
+    draw(&env, &client, session_id, Ability::Map.to_card_id());
+    client.play_ability(&session_id, &0);
+    assert_eq!(client.get_game(&session_id).turn_score, 10);
+    draw(&env, &client, session_id, Ability::Sword.to_card_id());
+    client.play_ability(&session_id, &0);
+    let state = client.get_game(&session_id);
+    assert_eq!(score_of(&state, &second), 0);
+    assert_eq!(state.active_player, second);
+    assert_eq!(state.discard, vec![&env, 7u32, 9u32, 5u32]);
+
+    // Oracle discards a busting Cups 9; Key and Chest double the banked Cups 10
+    for ability in [Ability::Key, Ability::Chest] {
+        draw(&env, &client, session_id, ability.to_card_id());
+        client.play_ability(&session_id, &0);
+    }
+    draw(&env, &client, session_id, 29);
+    draw(&env, &client, session_id, Ability::Oracle.to_card_id());
+    client.play_ability(&session_id, &0);
+    draw(&env, &client, session_id, 28);
+    let state = client.get_game(&session_id);
+    assert_eq!(state.active_player, second);
+    assert_eq!(state.turn_score, 10);
+    client.bank_cards(&session_id);
+    assert_eq!(score_of(&client.get_game(&session_id), &second), 20);
+}
+
+#[test]
+fn test_surrender() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
+    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_surrender(&session_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer)));
+
+    // Either player can concede at any point before the game ends
+    client.surrender(&session_id, &p2);
+    let state = client.get_game(&session_id);
+    assert_eq!(state.phase, Phase::Finished);
+
+    let result = client.try_surrender(&session_id, &p1);
+    assert_eq!(result, Err(Ok(Error::NotInPhase)));
+}
+
+#[test]
+fn test_mutual_draw() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    // Nothing to accept yet
+    assert_eq!(client.try_accept_draw(&session_id, &p2), Err(Ok(Error::InvalidMove)));
+
+    // The offer lapses once the turn passes
+    client.offer_draw(&session_id, &p1);
+    assert_eq!(client.try_accept_draw(&session_id, &p1), Err(Ok(Error::InvalidMove)));
+    client.bank_cards(&session_id);
+    assert_eq!(client.get_game(&session_id).draw_offer, None);
+    assert_eq!(client.try_accept_draw(&session_id, &p2), Err(Ok(Error::InvalidMove)));
+
+    client.offer_draw(&session_id, &p1);
+    client.accept_draw(&session_id, &p2);
+
+    // The hub has no draw outcome, so it is never sent a result
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("TIE"), session_id).into_val(&env),
+                p2.clone().into_val(&env),
+            ),
+        ]
+    );
+    let state = client.get_game(&session_id);
+    assert_eq!(state.phase, Phase::Tied);
+    assert_eq!(client.try_surrender(&session_id, &p1), Err(Ok(Error::NotInPhase)));
+}
+
+#[test]
+fn test_game_rules() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let p1_deck_root = Bytes::from_slice(&env, &[1u8; 32]);
+    let p2_deck_root = Bytes::from_slice(&env, &[2u8; 32]);
+
+    // Sessions without rules get the defaults
+    client.start_game(&1, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &None);
+    let state = client.get_game(&1);
+    assert_eq!(state.rules, GameRules { win_score: 60, max_busts: 3, deck_size: 48 });
+
+    // Out-of-bounds rules are rejected
+    for rules in [
+        GameRules { win_score: 10, max_busts: 3, deck_size: 48 },
+        GameRules { win_score: 60, max_busts: 0, deck_size: 48 },
+        GameRules { win_score: 60, max_busts: 11, deck_size: 48 },
+        GameRules { win_score: 60, max_busts: 3, deck_size: 49 },
+    ] {
+        let result = client.try_start_game(&2, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &Some(rules));
+        assert_eq!(result, Err(Ok(Error::InvalidRules)));
+    }
+
+    // Admin can widen the bounds to allow shorter games
+    let result = client.try_set_rule_bounds(&GameRuleBounds {
+        min_win_score: 30,
+        max_win_score: 20,
+        max_busts: 3,
+        max_deck_size: 48,
+    });
+    assert_eq!(result, Err(Ok(Error::InvalidRules)));
+    // Decks can't hold more than the 48 distinct cards
+    let result = client.try_set_rule_bounds(&GameRuleBounds {
+        min_win_score: 10,
+        max_win_score: 60,
+        max_busts: 3,
+        max_deck_size: 49,
+    });
+    assert_eq!(result, Err(Ok(Error::InvalidRules)));
+    client.set_rule_bounds(&GameRuleBounds { min_win_score: 10, max_win_score: 60, max_busts: 3, max_deck_size: 48 });
+
+    // One-bust game: the first bust loses
+    let rules = GameRules { win_score: 10, max_busts: 1, deck_size: 40 };
+    let session_id = 3u32;
+    client.start_game(&session_id, &p1, &p2, &100, &100, &p1_deck_root, &p2_deck_root, &Some(rules.clone()));
+    assert_eq!(client.get_game(&session_id).rules, rules);
+
+    reveal_seeds(&env, &client, session_id, &p1, &p2);
+
+    draw(&env, &client, session_id, 5);
+    draw(&env, &client, session_id, 7);
+    assert_eq!(client.get_game(&session_id).phase, Phase::Finished);
+}
+
+#[test]
+fn test_admin_functions() {
+    let (env, client, admin, _p1, _p2) = setup_test();
+
+    assert_eq!(client.get_admin(), admin);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+
+    let new_hub = env.register(MockGameHub, ());
+    client.set_hub(&new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+
+    // Fails because the WASM isn't uploaded, but confirms the entrypoint
+    let new_wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+    assert!(client.try_upgrade(&new_wasm_hash).is_err());
+}
+
+#[test]
+fn test_pause() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let deck_root = Bytes::from_slice(&env, &[1u8; 32]);
+    let result = client.try_start_game(&(session_id + 1), &p1, &p2, &100, &100, &deck_root, &deck_root, &None);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    let proof = mock_proof(&env, vec![&env]);
+    let merkle = MerkleProof { index: 0, siblings: vec![&env] };
+    assert_eq!(client.try_draw_card(&session_id, &5u32, &proof, &merkle), Err(Ok(Error::ContractPaused)));
+    assert_eq!(client.try_bank_cards(&session_id), Err(Ok(Error::ContractPaused)));
+
+    // Gameplay resumes once unpaused
+    client.unpause();
+    assert!(!client.is_paused());
+    client.bank_cards(&session_id);
+}
+
+#[test]
+fn test_turn_history() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    let first = client.get_game(&session_id).active_player;
+    let second = if first == p1 { p2.clone() } else { p1.clone() };
+
+    // Turn 1 banks Swords 6 + Coins 9, turn 2 busts on Swords 8
+    draw(&env, &client, session_id, 5);
+    draw(&env, &client, session_id, 18);
+    client.bank_cards(&session_id);
+    draw(&env, &client, session_id, 5);
+    draw(&env, &client, session_id, 7);
+
+    let turns = client.get_turns(&session_id, &0, &10);
+    assert_eq!(turns.len(), 2);
+
+    let banked = turns.get(0).unwrap();
+    assert_eq!(banked.turn_number, 1);
+    assert_eq!(banked.player, first);
+    assert_eq!(banked.cards, vec![&env, 5u32, 18u32]);
+    assert_eq!(banked.outcome, TurnOutcome::Banked);
+    assert_eq!(banked.score_delta, 15);
+
+    let busted = turns.get(1).unwrap();
+    assert_eq!(busted.turn_number, 2);
+    assert_eq!(busted.player, second);
+    assert_eq!(busted.cards, vec![&env, 5u32, 7u32]);
+    assert_eq!(busted.outcome, TurnOutcome::Busted);
+    assert_eq!(busted.score_delta, 0);
+
+    // Pages stop at the end of the log
+    let page = client.get_turns(&session_id, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), busted);
+    assert_eq!(client.get_turns(&session_id, &2, &10).len(), 0);
+}
+
+#[test]
+fn test_event_payloads() {
+    let (env, client, _admin, p1, p2) = setup_test();
+
+    let session_id = 12345u32;
+    setup_playing(&env, &client, session_id, &p1, &p2);
+
+    let first = client.get_game(&session_id).active_player;
+    let second = if first == p1 { p2.clone() } else { p1.clone() };
+    // (p1, p2) ordering of a value for the first and second player
+    let ordered = |first_value: u32, second_value: u32| {
+        if first == p1 { (first_value, second_value) } else { (second_value, first_value) }
+    };
+
+    // Turn 1 banks Swords 6 + Coins 9
+    draw(&env, &client, session_id, 5);
+    draw(&env, &client, session_id, 18);
+    client.bank_cards(&session_id);
+    let (p1_score, p2_score) = ordered(15, 0);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("BANK"), session_id).into_val(&env),
+                (first.clone(), 1u32, 15u32, p1_score, p2_score, 0u32, 0u32).into_val(&env),
+            ),
+        ]
+    );
+
+    // Turn 2: a Sword steals the banked Swords 6 into a turn holding Swords 8
+    draw(&env, &client, session_id, 7);
+    draw(&env, &client, session_id, Ability::Sword.to_card_id());
+    client.play_ability(&session_id, &0);
+    let (p1_score, p2_score) = ordered(9, 0);
+    let (p1_busts, p2_busts) = ordered(0, 1);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("ABILITY"), session_id).into_val(&env),
+                (Ability::Sword, 0u32).into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("BUST"), session_id).into_val(&env),
+                (second.clone(), 2u32, 5u32, 0u32, p1_score, p2_score, p1_busts, p2_busts).into_val(&env),
+            ),
+        ]
+    );
+}
+
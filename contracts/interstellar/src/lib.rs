@@ -13,8 +13,8 @@
 //! - Win condition determination
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror, 
-    contractimpl, contracttype, vec, panic_with_error
+    Address, Bytes, BytesN, Env, IntoVal, Val, Vec, contract, contractclient, contracterror, 
+    contractimpl, contracttype, vec, panic_with_error, symbol_short, token, xdr::ToXdr
 };
 
 mod verifier;
@@ -58,6 +58,25 @@ pub enum Error {
     InvalidShot = 8,
     InvalidDamage = 9,
     InvalidItemCollection = 10,
+    OutOfAmmo = 11,
+    PendingHitNotFound = 12,
+    PlayerDead = 13,
+    RespawnCooldown = 14,
+    ItemAlreadyCollected = 15,
+    WeaponCooldown = 16,
+    NoGrenades = 17,
+    StalePosition = 18,
+    MapNotFound = 19,
+    LoadoutNotFound = 20,
+    TimeoutNotReached = 21,
+    ContractPaused = 22,
+    VerificationKeyNotSet = 23,
+    InvalidWager = 24,
+    SessionAlreadyExists = 25,
+    ItemNotSpawned = 26,
+    RelayKeyNotSet = 27,
+    WinConditionNotMet = 28,
+    InvalidMatchParams = 29,
 }
 
 // ============================================================================
@@ -69,6 +88,7 @@ pub enum Error {
 pub enum GamePhase {
     Active,    // Game in progress
     Complete,  // Game finished
+    Cancelled,  // Abandoned before either player acted, wagers released
 }
 
 #[contracttype]
@@ -86,7 +106,19 @@ pub struct PlayerState {
     pub kills: u32,
     pub position_commitment: Option<Bytes>,  // Poseidon hash of position + salt
     pub weapon_type: u32,  // 0=pistol, 1=rifle, 2=shotgun, 3=sniper
-    pub ammo: u32,
+    pub ammo: u32,  // Rounds loaded in the current weapon
+    pub reserve_ammo: u32,  // Spare rounds available for reloading
+    pub respawn_turn: Option<u32>,  // Set while dead: first turn at which respawn is allowed
+    pub last_move_turn: Option<u32>,  // Turn of the player's most recent move
+    pub next_shot_turn: u32,  // First turn at which the weapon is off cooldown
+    pub grenades: u32,
+    pub position_turn: u32,  // Turn at which position_commitment was last updated
+    pub loadout_id: u32,  // Starting loadout, also used to restock on respawn
+    pub last_action_ledger: u32,  // Ledger of the player's most recent action
+    pub armor: u32,  // Remaining hits that will be mitigated
+    pub streak: u32,  // Kills since the player last died
+    pub damage_boost_percent: u32,  // Extra shot damage from a streak bonus
+    pub damage_boost_until_turn: u32,  // Shots fired before this turn are boosted
 }
 
 #[contracttype]
@@ -105,9 +137,16 @@ pub struct Game {
     pub kill_limit: u32,
     pub time_limit: u64,  // milliseconds
     pub start_time: u64,
+    pub start_ledger: u32,  // Ledger item spawn windows are measured from
+    pub item_seed: BytesN<32>,  // Seed the item spawn table is derived from
+    pub map_id: u32,
+    pub arena_half_size: i32,  // Arena bounds copied from the map at game start
+    pub escrow_token: Option<Address>,  // Wagers held in this SAC token instead of GameHub points
+    pub starting_health: i32,  // Health players start and respawn with, also the health-pack cap
+    pub starting_reserve_ammo: u32,  // Reserve ammo of the built-in default loadout
     
-    // Turn tracking
-    pub current_turn: u32,  // Increments with each action
+    // Turn tracking (player1 acts on even turns, player2 on odd turns)
+    pub current_turn: u32,  // Increments with each turn-consuming action
     pub last_actor: u32,  // 0 = player1, 1 = player2
     
     // Winner
@@ -115,6 +154,132 @@ pub struct Game {
     pub phase: GamePhase,
 }
 
+/// An item placed in the arena at game start
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ItemSpawn {
+    pub item_id: u32,
+    pub item_type: u32,  // 0=health, 1=ammo, 2=weapon, 3=shield, 4=armor
+    pub position: Position,
+}
+
+/// Per-session starting values, e.g. for hardcore or arcade modes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchParams {
+    pub starting_health: i32,
+    pub starting_reserve_ammo: u32,  // Applies to the built-in loadout; admin loadouts carry their own
+}
+
+/// Admin limits on the per-session starting values
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchParamBounds {
+    pub min_health: i32,
+    pub max_health: i32,
+    pub max_reserve_ammo: u32,
+}
+
+/// Starting equipment a player can pick before their first position commitment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Loadout {
+    pub weapon_type: u32,
+    pub reserve_ammo: u32,
+    pub grenades: u32,
+}
+
+/// Reward granted when a player's kill streak reaches `kills`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreakBonus {
+    pub kills: u32,
+    pub bonus_ammo: u32,  // Added to reserve ammo
+    pub damage_boost_percent: u32,  // Extra shot damage, 0 for none
+    pub boost_turns: u32,  // How many of the killer's own turns the damage boost lasts
+}
+
+/// A verified action as recorded in the session's action log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoggedAction {
+    Move,
+    Shot(bool),           // hit
+    Damage(u32),          // hit_id
+    Grenade(bool, bool),  // (target_in_radius, thrower_in_radius)
+    Reload,
+    Respawn,
+    Item(u32),            // item_id
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionRecord {
+    pub player: Address,
+    pub turn: u32,
+    pub ledger: u32,
+    pub action: LoggedAction,
+}
+
+/// Arena layout configured by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MapConfig {
+    pub half_size: i32,  // Positions lie within [-half_size, half_size] on the x and z axes
+    pub item_layout: Vec<ItemSpawn>,  // Fixed item spawns; empty = derive from the session seed
+}
+
+/// Combat statistics for one player in a match
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerMatchStats {
+    pub shots_fired: u32,
+    pub hits: u32,
+    pub damage_dealt: i32,
+    pub damage_taken: i32,
+    pub items_collected: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MatchStats {
+    pub player1: PlayerMatchStats,
+    pub player2: PlayerMatchStats,
+}
+
+/// Compact per-player view for HUD updates
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerSummary {
+    pub health: i32,
+    pub kills: u32,
+    pub ammo: u32,
+    pub reserve_ammo: u32,
+    pub weapon_type: u32,
+    pub armor: u32,
+}
+
+/// Per-weapon balance parameters
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeaponStats {
+    pub damage: i32,  // Health removed per hit
+    pub range: u32,  // Maximum hit distance, checked against the shot proof's range signal
+    pub ammo_cost: u32,  // Rounds consumed per shot
+    pub magazine_size: u32,  // Rounds loaded by a reload
+    pub cooldown_turns: u32,  // Own turns between shots (1 = every turn)
+}
+
+/// A verified hit awaiting its damage proof
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingHit {
+    pub shooter: Address,
+    pub target: Address,
+    pub weapon_type: u32,
+    pub target_commitment: Bytes,  // Target position commitment the hit was proven against
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct Groth16Proof {
@@ -123,16 +288,65 @@ pub struct Groth16Proof {
     pub pi_c: BytesN<64>,
 }
 
+/// Kind of action in a batch submitted with `submit_actions`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ActionKind {
+    Move,
+    Shoot,
+    Damage(u32),  // hit_id of the pending hit to resolve
+}
+
+/// An action and its proof, applied exactly as the matching single-action entrypoint would
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ActionWithProof {
+    pub kind: ActionKind,
+    pub proof: Groth16Proof,
+    pub public_signals: Vec<Bytes>,
+}
+
+/// An action signed by the acting player for submission by a relayer
+///
+/// The signature is an ed25519 signature over the XDR of
+/// `(contract_address, session_id, player, current_turn, action)` by the
+/// player's registered relay key.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RelayedAction {
+    pub player: Address,
+    pub action: ActionWithProof,
+    pub signature: BytesN<64>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
+    PendingHit(u32, u32),  // (session_id, hit_id) - hit_id is the turn of the shot
+    ItemSpawns(u32),       // Vec<ItemSpawn> for a session
+    ItemNullifier(u32, u32),  // (session_id, item_id) - ledger the item was last collected at
+    MatchStats(u32),       // MatchStats for a session
+    ActionCount(u32),      // Number of entries in a session's action log
+    Action(u32, u32),      // (session_id, index) - ActionRecord
     GameHubAddress,
     Admin,
     ShootingVerificationKey,  // VK for shooting circuit
     DamageVerificationKey,    // VK for damage circuit
     ItemVerificationKey,      // VK for item collection circuit
     WinVerificationKey,       // VK for win condition circuit
+    MovementVerificationKey,  // VK for movement (speed-limit) circuit
+    GrenadeVerificationKey,   // VK for grenade blast-radius circuit
+    ShootDamageVerificationKey,  // VK for combined shoot-and-damage circuit
+    WeaponTable,              // Vec<WeaponStats> indexed by weapon_type
+    Map(u32),                 // MapConfig by map id
+    Loadouts,                 // Vec<Loadout> indexed by loadout id
+    InactivityTimeout,        // Ledgers without action before the opponent can claim the win
+    Paused,                   // bool, set by the admin to halt gameplay
+    Rating(Address),          // Persistent ELO-style rating per player
+    StreakBonuses,            // Vec<StreakBonus>, at most one per streak length
+    RelayKey(Address),        // Persistent ed25519 public key a player signs relayed actions with
+    MatchParamBounds,         // MatchParamBounds for per-session starting values
 }
 
 // ============================================================================
@@ -142,6 +356,130 @@ pub enum DataKey {
 /// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+// ============================================================================
+// Weapons
+// ============================================================================
+
+/// Number of weapon types (0=pistol, 1=rifle, 2=shotgun, 3=sniper)
+const WEAPON_COUNT: u32 = 4;
+
+/// Default damage per hit, indexed by weapon_type
+const DEFAULT_WEAPON_DAMAGE: [i32; 4] = [20, 30, 45, 80];
+
+/// Default maximum range, indexed by weapon_type
+const DEFAULT_WEAPON_RANGE: [u32; 4] = [30, 60, 15, 120];
+
+/// Default rounds consumed per shot, indexed by weapon_type
+const DEFAULT_WEAPON_AMMO_COST: [u32; 4] = [1, 3, 2, 1];
+
+/// Default magazine capacity, indexed by weapon_type
+const DEFAULT_WEAPON_MAGAZINE_SIZE: [u32; 4] = [12, 30, 8, 5];
+
+/// Default cooldown in the shooter's own turns, indexed by weapon_type
+const DEFAULT_WEAPON_COOLDOWN_TURNS: [u32; 4] = [1, 1, 2, 3];
+
+/// Spare rounds each player starts with unless the session overrides it
+const STARTING_RESERVE_AMMO: u32 = 50;
+
+/// Extra loaded rounds a missed shot costs, so spamming long shots isn't free
+const MISS_EXTRA_AMMO_COST: u32 = 1;
+
+/// Reserve rounds granted to a player who is shot at and missed
+const EVASION_REWARD_AMMO: u32 = 1;
+
+// ============================================================================
+// Items
+// ============================================================================
+
+/// Number of items spawned in the arena per session
+const ITEM_SPAWN_COUNT: u32 = 8;
+
+/// Number of item types (0=health, 1=ammo, 2=weapon, 3=shield, 4=armor)
+const ITEM_TYPE_COUNT: u32 = 5;
+
+/// Armor charges granted by an armor item, and the most a player can hold
+const ARMOR_PER_ITEM: u32 = 2;
+const MAX_ARMOR: u32 = 4;
+
+/// Percentage of incoming shot damage absorbed while a player has armor
+const ARMOR_MITIGATION_PERCENT: u32 = 25;
+
+/// Ledgers between spawns of each item type; an item first appears one interval
+/// after game start and, once collected, reappears at the next interval boundary
+const ITEM_SPAWN_INTERVAL_LEDGERS: [u32; ITEM_TYPE_COUNT as usize] = [50, 30, 120, 100, 80];
+
+/// Arena bounds of the built-in default map
+const ARENA_HALF_SIZE: i32 = 50;
+
+/// Map used when the admin hasn't configured one (seeded items, default bounds)
+const DEFAULT_MAP_ID: u32 = 0;
+
+/// Grenades each player starts with
+const STARTING_GRENADES: u32 = 2;
+
+/// Grenade blast radius, public input to the grenade circuit
+const GRENADE_BLAST_RADIUS: u32 = 8;
+
+/// Splash damage dealt to each player inside the blast radius
+const GRENADE_DAMAGE: i32 = 40;
+
+/// Turns a position commitment stays fresh; shots by or at a player whose
+/// commitment is older than this are rejected (3 of each player's own turns)
+const POSITION_FRESHNESS_TURNS: u32 = 6;
+
+/// Default ledgers a player may go without acting on their turn (~1 hour)
+const DEFAULT_INACTIVITY_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Ledgers after game start before an untouched session can be cancelled (~10 minutes)
+const CANCEL_GRACE_LEDGERS: u32 = 120;
+
+/// Hard cap on turns; the match is resolved by kills, then health, when reached
+const MAX_TURNS: u32 = 1_000;
+
+/// Maximum number of action records returned by one get_actions call
+const MAX_ACTIONS_PAGE: u32 = 50;
+
+/// Maximum distance a player may move per turn, public input to the movement circuit
+const MAX_MOVE_DISTANCE: u32 = 5;
+
+/// Default kill-streak rewards: ammo at 3 kills, a damage boost at 5
+const DEFAULT_STREAK_AMMO_KILLS: u32 = 3;
+const DEFAULT_STREAK_AMMO: u32 = 20;
+const DEFAULT_STREAK_BOOST_KILLS: u32 = 5;
+const DEFAULT_STREAK_BOOST_PERCENT: u32 = 25;
+const DEFAULT_STREAK_BOOST_TURNS: u32 = 6;
+
+/// Health each player starts (and respawns) with unless the session overrides it
+const BASE_HEALTH: i32 = 100;
+
+/// Default bounds on per-session starting values
+const DEFAULT_MIN_STARTING_HEALTH: i32 = 25;
+const DEFAULT_MAX_STARTING_HEALTH: i32 = 300;
+const DEFAULT_MAX_STARTING_RESERVE_AMMO: u32 = 200;
+
+/// Own turns a dead player sits out before respawning.
+/// Those turns are skipped, so the turn order keeps moving while they wait.
+const RESPAWN_COOLDOWN_TURNS: u32 = 2;
+
+/// TTL for player ratings in persistent storage (~30 days)
+const RATING_TTL_LEDGERS: u32 = 518_400;
+
+/// TTL for players' relay keys in persistent storage (~30 days)
+const RELAY_KEY_TTL_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Ratings
+// ============================================================================
+
+/// Rating assigned to players who have not finished a match yet
+const INITIAL_RATING: i32 = 1200;
+
+/// Maximum rating change per match
+const RATING_K_FACTOR: i32 = 32;
+
+/// Rating differences beyond this are clamped (FIDE 400-point rule)
+const RATING_MAX_DIFF: i32 = 400;
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -169,6 +507,11 @@ impl InterstellarContract {
     /// * `player2_points` - Points committed by player 2
     /// * `kill_limit` - Number of kills to win (default: 10)
     /// * `time_limit` - Time limit in milliseconds (default: 300000 = 5 minutes)
+    /// * `map_id` - Arena to play on (0 = default map unless overridden by the admin)
+    /// * `escrow_token` - If set, the points are wagered in this SAC token and held by
+    ///   this contract (paid to the winner) instead of being locked in the GameHub
+    /// * `params` - Starting health and reserve ammo (default: 100 / 50), within the
+    ///   admin's bounds
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -178,77 +521,107 @@ impl InterstellarContract {
         player2_points: i128,
         kill_limit: u32,
         time_limit: u64,
+        map_id: u32,
+        escrow_token: Option<Address>,
+        params: Option<MatchParams>,
     ) -> Result<(), Error> {
         // Prevent self-play
         if player1 == player2 {
             panic_with_error!(&env, Error::NotPlayer);
         }
 
-        // Require authentication from both players
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game in Active phase
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        // Each player authorizes the session, their own stake and every match setting
+        let settings: Vec<Val> = vec![
+            &env,
+            kill_limit.into_val(&env),
+            time_limit.into_val(&env),
+            map_id.into_val(&env),
+            escrow_token.into_val(&env),
+            params.into_val(&env),
+        ];
+        let mut player1_args: Vec<Val> = vec![&env, session_id.into_val(&env), player1_points.into_val(&env)];
+        player1_args.append(&settings);
+        let mut player2_args: Vec<Val> = vec![&env, session_id.into_val(&env), player2_points.into_val(&env)];
+        player2_args.append(&settings);
+        player1.require_auth_for_args(player1_args);
+        player2.require_auth_for_args(player2_args);
+
+        Self::create_game(
+            env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_state: PlayerState {
-                health: 100,
-                kills: 0,
-                position_commitment: None,
-                weapon_type: 0,  // Start with pistol
-                ammo: 50,
-            },
-            player2_state: PlayerState {
-                health: 100,
-                kills: 0,
-                position_commitment: None,
-                weapon_type: 0,  // Start with pistol
-                ammo: 50,
-            },
             kill_limit,
             time_limit,
-            start_time: env.ledger().timestamp(),
-            current_turn: 0,
-            last_actor: 0,
-            winner: None,
-            phase: GamePhase::Active,
-        };
+            map_id,
+            escrow_token,
+            params.unwrap_or(MatchParams {
+                starting_health: BASE_HEALTH,
+                starting_reserve_ammo: STARTING_RESERVE_AMMO,
+            }),
+        )
+    }
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
+    /// Start a new session between the same players with the settings of a finished game
+    ///
+    /// Both players authorize the new session, their stakes and the copied
+    /// settings, as in `start_game` (with `params` always given explicitly).
+    ///
+    /// # Arguments
+    /// * `session_id` - The completed session to copy settings from
+    /// * `new_session_id` - Unique identifier for the rematch
+    pub fn rematch(env: Env, session_id: u32, new_session_id: u32) -> Result<(), Error> {
+        let game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
 
-        Ok(())
+        if game.phase != GamePhase::Complete {
+            return Err(Error::InvalidAction);
+        }
+
+        let params = MatchParams {
+            starting_health: game.starting_health,
+            starting_reserve_ammo: game.starting_reserve_ammo,
+        };
+        let settings: Vec<Val> = vec![
+            &env,
+            game.kill_limit.into_val(&env),
+            game.time_limit.into_val(&env),
+            game.map_id.into_val(&env),
+            game.escrow_token.into_val(&env),
+            Some(params.clone()).into_val(&env),
+        ];
+        let mut player1_args: Vec<Val> = vec![&env, new_session_id.into_val(&env), game.player1_points.into_val(&env)];
+        player1_args.append(&settings);
+        let mut player2_args: Vec<Val> = vec![&env, new_session_id.into_val(&env), game.player2_points.into_val(&env)];
+        player2_args.append(&settings);
+        game.player1.require_auth_for_args(player1_args);
+        game.player2.require_auth_for_args(player2_args);
+
+        Self::create_game(
+            env,
+            new_session_id,
+            game.player1,
+            game.player2,
+            game.player1_points,
+            game.player2_points,
+            game.kill_limit,
+            game.time_limit,
+            game.map_id,
+            game.escrow_token,
+            params,
+        )
     }
 
     /// Submit a position commitment (Poseidon hash of x, y, z, salt)
     ///
+    /// Only sets the initial spawn position; later changes go through
+    /// `move_player` so they are bound by the speed limit.
+    ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player
@@ -260,6 +633,7 @@ impl InterstellarContract {
         position_commitment: Bytes,
     ) -> Result<(), Error> {
         player.require_auth();
+        Self::require_not_paused(&env)?;
 
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -273,14 +647,76 @@ impl InterstellarContract {
         }
 
         // Store commitment for the appropriate player
-        if player == game.player1 {
-            game.player1_state.position_commitment = Some(position_commitment);
+        let player_state = if player == game.player1 {
+            &mut game.player1_state
+        } else if player == game.player2 {
+            &mut game.player2_state
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if player_state.position_commitment.is_some() {
+            return Err(Error::InvalidPosition);
+        }
+        player_state.position_commitment = Some(position_commitment);
+        player_state.position_turn = game.current_turn;
+        player_state.last_action_ledger = env.ledger().sequence();
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Pick a starting loadout from the admin-configured loadout table
+    ///
+    /// Only allowed before the player's first position commitment.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player
+    /// * `loadout_id` - Index into the loadout table
+    pub fn select_loadout(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        loadout_id: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let player_state = if player == game.player1 {
+            &mut game.player1_state
         } else if player == game.player2 {
-            game.player2_state.position_commitment = Some(position_commitment);
+            &mut game.player2_state
         } else {
             return Err(Error::NotPlayer);
+        };
+        if player_state.position_commitment.is_some() {
+            return Err(Error::InvalidAction);
         }
 
+        let loadout = Self::loadout(&env, loadout_id, game.starting_reserve_ammo)?;
+        player_state.weapon_type = loadout.weapon_type;
+        player_state.ammo = Self::weapon_stats(&env, loadout.weapon_type).magazine_size;
+        player_state.reserve_ammo = loadout.reserve_ammo;
+        player_state.grenades = loadout.grenades;
+        player_state.loadout_id = loadout_id;
+        player_state.last_action_ledger = env.ledger().sequence();
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
         env.storage()
@@ -290,18 +726,55 @@ impl InterstellarContract {
         Ok(())
     }
 
+    /// Move to a new position with a ZK proof that the move respects the speed limit
+    ///
+    /// A player may move once during each of their turns; moving does not end the turn.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the moving player
+    /// * `proof` - Groth16 ZK proof of a valid move
+    /// * `public_signals` - Public signals (old_commitment, new_commitment, max_distance, bounds)
+    ///
+    /// # Public Signals Format:
+    /// [0] = old_position_commitment (must match the stored commitment)
+    /// [1] = new_position_commitment
+    /// [2] = max_distance (must equal MAX_MOVE_DISTANCE)
+    /// [3] = arena_half_size (must equal the session's arena bounds)
+    pub fn move_player(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::apply_move(env, session_id, player, proof, public_signals)
+    }
+
     /// Execute a shooting action with ZK proof
     ///
+    /// Shooting consumes the shooter's turn; players alternate, starting with player1.
+    /// Both players' position commitments must have been updated within the last
+    /// POSITION_FRESHNESS_TURNS turns.
+    /// Each shot uses rounds from the loaded magazine according to the weapon type,
+    /// and the weapon can't fire again until its cooldown has passed.
+    /// A hit is recorded as a pending hit whose id is the turn number of the shot
+    /// (`current_turn` before this call); `apply_damage` must reference it.
+    ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `shooter` - Address of the shooting player
     /// * `proof` - Groth16 ZK proof of valid shot
-    /// * `public_signals` - Public signals (shooter_commitment, target_commitment, hit)
+    /// * `public_signals` - Public signals (shooter_commitment, target_commitment, hit, bounds, range)
     ///
     /// # Public Signals Format:
-    /// [0] = shooter_position_commitment
-    /// [1] = target_position_commitment
+    /// [0] = shooter_position_commitment (must match the shooter's stored commitment)
+    /// [1] = target_position_commitment (must match the target's stored commitment)
     /// [2] = hit (0=miss, 1=hit)
+    /// [3] = arena_half_size (must equal the session's arena bounds)
+    /// [4] = max_range (must equal the shooter's weapon range)
     pub fn shoot(
         env: Env,
         session_id: u32,
@@ -310,6 +783,40 @@ impl InterstellarContract {
         public_signals: Vec<Bytes>,
     ) -> Result<bool, Error> {
         shooter.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::apply_shot(env, session_id, shooter, proof, public_signals)
+    }
+
+    /// Shoot and apply the resulting damage in one step with a combined ZK proof
+    ///
+    /// Follows the same turn, ammo, cooldown and position rules as `shoot`, but a hit
+    /// is resolved immediately instead of leaving a pending hit for `apply_damage`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `shooter` - Address of the shooting player
+    /// * `proof` - Groth16 ZK proof of hit detection and damage
+    /// * `public_signals` - Public signals (shot signals followed by damage signals)
+    ///
+    /// # Public Signals Format:
+    /// [0] = shooter_position_commitment (must match the shooter's stored commitment)
+    /// [1] = target_position_commitment (must match the target's stored commitment)
+    /// [2] = hit (0=miss, 1=hit)
+    /// [3] = arena_half_size (must equal the session's arena bounds)
+    /// [4] = max_range (must equal the shooter's weapon range)
+    /// [5] = weapon_type (must match the shooter's weapon)
+    /// [6] = old_health (must match the target's stored health)
+    /// [7] = new_health
+    /// [8] = mitigation_percent (ARMOR_MITIGATION_PERCENT if the target has armor, else 0)
+    pub fn shoot_and_apply(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<bool, Error> {
+        shooter.require_auth();
+        Self::require_not_paused(&env)?;
 
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -322,25 +829,61 @@ impl InterstellarContract {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Determine shooter and target
         let is_player1 = shooter == game.player1;
         if !is_player1 && shooter != game.player2 {
             return Err(Error::NotPlayer);
         }
+        Self::prepare_shot(&env, &mut game, is_player1)?;
 
-        // Verify proof using shooting verification key
-        Self::verify_shooting_proof(&env, proof, public_signals.clone())?;
+        // Verify proof using combined shoot-and-damage verification key
+        Self::verify_shoot_damage_proof(&env, proof, public_signals.clone())?;
 
-        // Extract hit result from public signals
-        if public_signals.len() < 3 {
+        if public_signals.len() < 9 {
             return Err(Error::InvalidProof);
         }
+        if Self::bytes_to_i32(&public_signals.get(3).unwrap()) != game.arena_half_size {
+            return Err(Error::InvalidShot);
+        }
+        Self::check_shot_range(&env, &game, is_player1, &public_signals.get(4).unwrap())?;
+        Self::check_shot_positions(&game, is_player1, public_signals.get(0).unwrap(), public_signals.get(1).unwrap())?;
 
         let hit = Self::bytes_to_u32(&public_signals.get(2).unwrap()) == 1;
+        let damage = if hit {
+            let (shooter_state, target_state) = if is_player1 {
+                (&game.player1_state, &game.player2_state)
+            } else {
+                (&game.player2_state, &game.player1_state)
+            };
+            let weapon_type = Self::bytes_to_u32(&public_signals.get(5).unwrap());
+            let old_health = Self::bytes_to_i32(&public_signals.get(6).unwrap());
+            let new_health = Self::bytes_to_i32(&public_signals.get(7).unwrap());
+            let mitigation = Self::bytes_to_u32(&public_signals.get(8).unwrap());
+            let base = Self::boosted_damage(
+                shooter_state,
+                Self::weapon_stats(&env, weapon_type).damage,
+                game.current_turn,
+            );
+            let expected = Self::mitigated_damage(target_state, base, mitigation)?;
+            if weapon_type != shooter_state.weapon_type
+                || old_health != target_state.health
+                || old_health - new_health != expected
+            {
+                return Err(Error::InvalidDamage);
+            }
+            old_health - new_health
+        } else {
+            0
+        };
 
-        // Update turn counter
-        game.current_turn += 1;
-        game.last_actor = if is_player1 { 0 } else { 1 };
+        if !hit {
+            Self::settle_miss(&mut game, is_player1);
+        }
+        Self::record_shot(&env, session_id, &game, is_player1, &shooter, hit);
+        if hit {
+            Self::consume_armor(&mut game, !is_player1);
+            Self::inflict_damage(&env, session_id, &mut game, !is_player1, damage, false);
+        }
+        Self::end_turn(&env, session_id, &mut game, is_player1);
 
         // Store updated game
         env.storage().temporary().set(&key, &game);
@@ -351,25 +894,17 @@ impl InterstellarContract {
         Ok(hit)
     }
 
-    /// Apply damage with ZK proof
+    /// Reload the current weapon from reserve ammo
+    ///
+    /// Reloading consumes the player's turn.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `target` - Address of the player taking damage
-    /// * `proof` - Groth16 ZK proof of valid damage calculation
-    /// * `public_signals` - Public signals (old_health, new_health, weapon_type)
-    ///
-    /// # Public Signals Format:
-    /// [0] = old_health
-    /// [1] = new_health
-    /// [2] = weapon_type
-    pub fn apply_damage(
-        env: Env,
-        session_id: u32,
-        target: Address,
-        proof: Groth16Proof,
-        public_signals: Vec<Bytes>,
-    ) -> Result<(), Error> {
+    /// * `player` - Address of the reloading player
+    pub fn reload(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -381,36 +916,212 @@ impl InterstellarContract {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Verify proof using damage verification key
-        Self::verify_damage_proof(&env, proof, public_signals.clone())?;
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        Self::check_turn(&game, is_player1)?;
 
-        // Extract damage info from public signals
-        if public_signals.len() < 3 {
+        let player_state = if is_player1 {
+            &mut game.player1_state
+        } else {
+            &mut game.player2_state
+        };
+        if player_state.health <= 0 {
+            return Err(Error::PlayerDead);
+        }
+        let magazine = Self::weapon_stats(&env, player_state.weapon_type).magazine_size;
+        if player_state.ammo >= magazine || player_state.reserve_ammo == 0 {
+            return Err(Error::InvalidAction);
+        }
+        let rounds = (magazine - player_state.ammo).min(player_state.reserve_ammo);
+        player_state.ammo += rounds;
+        player_state.reserve_ammo -= rounds;
+        let ammo = player_state.ammo;
+
+        Self::log_action(&env, session_id, game.current_turn, &player, LoggedAction::Reload);
+        Self::end_turn(&env, session_id, &mut game, is_player1);
+        env.events().publish((symbol_short!("RELOAD"), session_id), (player, ammo));
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Throw a grenade with a ZK proof of which players are inside the blast radius
+    ///
+    /// Consumes a grenade and the thrower's turn. Every player inside the radius,
+    /// including the thrower, takes splash damage.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the throwing player
+    /// * `proof` - Groth16 ZK proof of the blast
+    /// * `public_signals` - Public signals (thrower/target commitments, radius, hits)
+    ///
+    /// # Public Signals Format:
+    /// [0] = thrower_position_commitment
+    /// [1] = target_position_commitment
+    /// [2] = blast_radius (must equal GRENADE_BLAST_RADIUS)
+    /// [3] = target_in_radius (0=no, 1=yes)
+    /// [4] = thrower_in_radius (0=no, 1=yes)
+    pub fn throw_grenade(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        Self::check_turn(&game, is_player1)?;
+        if game.player1_state.health <= 0 || game.player2_state.health <= 0 {
+            return Err(Error::PlayerDead);
+        }
+        Self::check_fresh_position(&game, &game.player1_state)?;
+        Self::check_fresh_position(&game, &game.player2_state)?;
+
+        let (thrower_state, target_state) = if is_player1 {
+            (&game.player1_state, &game.player2_state)
+        } else {
+            (&game.player2_state, &game.player1_state)
+        };
+        if thrower_state.grenades == 0 {
+            return Err(Error::NoGrenades);
+        }
+
+        // Verify proof using grenade verification key
+        Self::verify_grenade_proof(&env, proof, public_signals.clone())?;
+
+        if public_signals.len() < 5 {
             return Err(Error::InvalidProof);
         }
 
-        let new_health = Self::bytes_to_i32(&public_signals.get(1).unwrap());
+        // The blast must be proven against both players' current positions
+        if thrower_state.position_commitment != Some(public_signals.get(0).unwrap())
+            || target_state.position_commitment != Some(public_signals.get(1).unwrap())
+        {
+            return Err(Error::InvalidPosition);
+        }
+        if Self::bytes_to_u32(&public_signals.get(2).unwrap()) != GRENADE_BLAST_RADIUS {
+            return Err(Error::InvalidProof);
+        }
+        let target_hit = Self::bytes_to_u32(&public_signals.get(3).unwrap()) == 1;
+        let thrower_hit = Self::bytes_to_u32(&public_signals.get(4).unwrap()) == 1;
 
-        // Update target's health
-        let is_player1 = target == game.player1;
         if is_player1 {
-            game.player1_state.health = new_health;
-            
-            // Check if player died
-            if new_health <= 0 {
-                game.player2_state.kills += 1;
-            }
-        } else if target == game.player2 {
-            game.player2_state.health = new_health;
-            
-            // Check if player died
-            if new_health <= 0 {
-                game.player1_state.kills += 1;
-            }
+            game.player1_state.grenades -= 1;
+        } else {
+            game.player2_state.grenades -= 1;
+        }
+        Self::log_action(
+            &env,
+            session_id,
+            game.current_turn,
+            &player,
+            LoggedAction::Grenade(target_hit, thrower_hit),
+        );
+        env.events().publish(
+            (symbol_short!("GRENADE"), session_id),
+            (player, target_hit, thrower_hit),
+        );
+
+        if target_hit {
+            Self::inflict_damage(&env, session_id, &mut game, !is_player1, GRENADE_DAMAGE, false);
+        }
+        if thrower_hit && game.phase == GamePhase::Active {
+            Self::inflict_damage(&env, session_id, &mut game, is_player1, GRENADE_DAMAGE, true);
+        }
+
+        Self::end_turn(&env, session_id, &mut game, is_player1);
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Respawn a dead player at a new position
+    ///
+    /// Allowed once the respawn cooldown (counted in turns) has elapsed; the dead
+    /// player's turns are skipped until then. Restores base health, a full
+    /// magazine and the starting reserve ammo; the weapon is kept. Respawning does
+    /// not consume a turn.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the dead player
+    /// * `position_commitment` - Poseidon hash of the respawn position + salt
+    pub fn respawn(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        position_commitment: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let (starting_health, starting_reserve_ammo) = (game.starting_health, game.starting_reserve_ammo);
+        let player_state = if player == game.player1 {
+            &mut game.player1_state
+        } else if player == game.player2 {
+            &mut game.player2_state
         } else {
             return Err(Error::NotPlayer);
+        };
+
+        let respawn_turn = player_state.respawn_turn.ok_or(Error::InvalidAction)?;
+        if game.current_turn < respawn_turn {
+            return Err(Error::RespawnCooldown);
         }
 
+        player_state.health = starting_health;
+        player_state.ammo = Self::weapon_stats(&env, player_state.weapon_type).magazine_size;
+        player_state.reserve_ammo = Self::loadout(&env, player_state.loadout_id, starting_reserve_ammo)
+            .map(|loadout| loadout.reserve_ammo)
+            .unwrap_or(starting_reserve_ammo);
+        player_state.position_commitment = Some(position_commitment);
+        player_state.position_turn = game.current_turn;
+        player_state.respawn_turn = None;
+        player_state.armor = 0;
+        player_state.last_action_ledger = env.ledger().sequence();
+        Self::log_action(&env, session_id, game.current_turn, &player, LoggedAction::Respawn);
+        env.events().publish((symbol_short!("RESPAWN"), session_id), player);
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
         env.storage()
@@ -420,18 +1131,164 @@ impl InterstellarContract {
         Ok(())
     }
 
+    /// Apply damage with ZK proof
+    ///
+    /// Consumes the pending hit recorded by `shoot`, so each verified hit deals
+    /// damage exactly once and only to the player who was shot. The hit lapses
+    /// once the target moves or respawns away from the position it was proven
+    /// against.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `shooter` - Address of the player who landed the hit
+    /// * `hit_id` - Id of the pending hit (turn number of the shot)
+    /// * `proof` - Groth16 ZK proof of valid damage calculation
+    /// * `public_signals` - Public signals (old_health, new_health, weapon_type, target_commitment)
+    ///
+    /// # Public Signals Format:
+    /// [0] = old_health (must match the target's stored health)
+    /// [1] = new_health
+    /// [2] = weapon_type
+    /// [3] = target_position_commitment (must match the commitment the hit was proven
+    ///       against, which must still be the target's stored commitment)
+    /// [4] = mitigation_percent (ARMOR_MITIGATION_PERCENT if the target has armor, else 0)
+    pub fn apply_damage(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        hit_id: u32,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        shooter.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::apply_hit_damage(env, session_id, shooter, hit_id, proof, public_signals)
+    }
+
+    /// Verify and apply several actions in one transaction
+    ///
+    /// Actions are applied in order with the same rules as `move_player`, `shoot`
+    /// and `apply_damage`; if any action fails the whole batch is reverted.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the acting player
+    /// * `actions` - Actions to apply, in order
+    pub fn submit_actions(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        actions: Vec<ActionWithProof>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        if actions.is_empty() {
+            return Err(Error::InvalidAction);
+        }
+
+        for action in actions.iter() {
+            Self::apply_action(&env, session_id, &player, action)?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply actions signed by the players but submitted by any address
+    ///
+    /// Lets a game server batch both players' actions and pay the fees. Each
+    /// signature is checked against this contract, the acting player and the turn
+    /// at which its action is applied, so a signed action can't be replayed later
+    /// in the match, on another deployment or on behalf of another player. An
+    /// invalid signature aborts the transaction.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `actions` - Signed actions to apply, in order
+    pub fn relay_actions(env: Env, session_id: u32, actions: Vec<RelayedAction>) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+
+        if actions.is_empty() {
+            return Err(Error::InvalidAction);
+        }
+
+        for relayed in actions.iter() {
+            let game: Game = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Game(session_id))
+                .ok_or(Error::GameNotFound)?;
+            let public_key: BytesN<32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RelayKey(relayed.player.clone()))
+                .ok_or(Error::RelayKeyNotSet)?;
+
+            let payload = (
+                env.current_contract_address(),
+                session_id,
+                relayed.player.clone(),
+                game.current_turn,
+                relayed.action.clone(),
+            )
+                .to_xdr(&env);
+            env.crypto().ed25519_verify(&public_key, &payload, &relayed.signature);
+
+            Self::apply_action(&env, session_id, &relayed.player, relayed.action)?;
+        }
+
+        Ok(())
+    }
+
+    /// Register the ed25519 public key used to sign relayed actions
+    ///
+    /// `signature` proves possession of the key: it must be the key's signature
+    /// over the XDR of `(contract_address, player)`, so a player can't register
+    /// a key they don't hold. An invalid signature aborts the transaction.
+    pub fn set_relay_key(env: Env, player: Address, public_key: BytesN<32>, signature: BytesN<64>) {
+        player.require_auth();
+
+        let message = (env.current_contract_address(), player.clone()).to_xdr(&env);
+        env.crypto().ed25519_verify(&public_key, &message, &signature);
+
+        let key = DataKey::RelayKey(player);
+        env.storage().persistent().set(&key, &public_key);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RELAY_KEY_TTL_LEDGERS, RELAY_KEY_TTL_LEDGERS);
+    }
+
+    /// Get a player's registered relay key
+    pub fn get_relay_key(env: Env, player: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::RelayKey(player))
+    }
+
+    /// Get a pending hit awaiting its damage proof
+    pub fn get_pending_hit(env: Env, session_id: u32, hit_id: u32) -> Option<PendingHit> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::PendingHit(session_id, hit_id))
+    }
+
     /// Collect an item with ZK proof
     ///
+    /// The item must exist in the session's spawn table and each item can only be
+    /// collected once; a nullifier is recorded on collection. Items can only be
+    /// picked up on the player's own turn, from the position they last committed,
+    /// and the proof must be made against the item's spawn position.
+    ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player collecting the item
     /// * `proof` - Groth16 ZK proof of valid item collection
-    /// * `public_signals` - Public signals (player_position, item_type, collected)
+    /// * `public_signals` - Public signals (player_position, item_type, collected, item_id, item_position)
     ///
     /// # Public Signals Format:
-    /// [0] = player_position_commitment
-    /// [1] = item_type (0=health, 1=ammo, 2=weapon, 3=shield)
+    /// [0] = player_position_commitment (must match the player's stored commitment)
+    /// [1] = item_type (0=health, 1=ammo, 2=weapon, 3=shield, 4=armor)
     /// [2] = collected (0=no, 1=yes)
+    /// [3] = item_id (index into the session's spawn table)
+    /// [4..6] = item_x, item_y, item_z (must equal the item's spawn position)
     pub fn collect_item(
         env: Env,
         session_id: u32,
@@ -440,6 +1297,7 @@ impl InterstellarContract {
         public_signals: Vec<Bytes>,
     ) -> Result<(), Error> {
         player.require_auth();
+        Self::require_not_paused(&env)?;
 
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -452,51 +1310,110 @@ impl InterstellarContract {
             return Err(Error::GameAlreadyEnded);
         }
 
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        Self::check_turn(&game, is_player1)?;
+
         // Verify proof using item verification key
         Self::verify_item_proof(&env, proof, public_signals.clone())?;
 
         // Extract item info from public signals
-        if public_signals.len() < 3 {
+        if public_signals.len() < 7 {
             return Err(Error::InvalidProof);
         }
 
+        // The pickup must be proven from the player's current position
+        let player_state = if is_player1 { &game.player1_state } else { &game.player2_state };
+        Self::check_committed_position(player_state, &public_signals.get(0).unwrap())?;
+
         let item_type = Self::bytes_to_u32(&public_signals.get(1).unwrap());
         let collected = Self::bytes_to_u32(&public_signals.get(2).unwrap()) == 1;
+        let item_id = Self::bytes_to_u32(&public_signals.get(3).unwrap());
 
         if !collected {
             return Err(Error::InvalidItemCollection);
         }
 
+        // The item must be in the spawn table and currently spawned
+        let items: Vec<ItemSpawn> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ItemSpawns(session_id))
+            .ok_or(Error::InvalidItemCollection)?;
+        let item = items.get(item_id).ok_or(Error::InvalidItemCollection)?;
+        if item.item_type != item_type {
+            return Err(Error::InvalidItemCollection);
+        }
+        Self::check_item_position(&item, &public_signals)?;
+        let nullifier_key = DataKey::ItemNullifier(session_id, item_id);
+        let collected_at: Option<u32> = env.storage().temporary().get(&nullifier_key);
+        if env.ledger().sequence() < Self::item_spawn_ledger(&game, item_type, collected_at) {
+            return Err(if collected_at.is_some() {
+                Error::ItemAlreadyCollected
+            } else {
+                Error::ItemNotSpawned
+            });
+        }
+
         // Apply item effect
-        let is_player1 = player == game.player1;
+        let max_health = game.starting_health;
         let player_state = if is_player1 {
             &mut game.player1_state
-        } else if player == game.player2 {
-            &mut game.player2_state
         } else {
-            return Err(Error::NotPlayer);
+            &mut game.player2_state
         };
+        if player_state.health <= 0 {
+            return Err(Error::PlayerDead);
+        }
 
         match item_type {
             0 => {
-                // Health pack: +25 health (max 100)
-                player_state.health = (player_state.health + 25).min(100);
+                // Health pack: +25 health (up to the session's starting health)
+                player_state.health = (player_state.health + 25).min(max_health);
             }
             1 => {
-                // Ammo: +30 ammo
-                player_state.ammo += 30;
+                // Ammo: +30 reserve ammo
+                player_state.reserve_ammo += 30;
             }
             2 => {
                 // Weapon upgrade
                 player_state.weapon_type = (player_state.weapon_type + 1).min(3);
             }
             3 => {
-                // Shield: +50 health (can exceed 100)
+                // Shield: +50 health (can exceed the starting health)
                 player_state.health += 50;
             }
+            4 => {
+                // Armor: mitigates the next few hits
+                player_state.armor = (player_state.armor + ARMOR_PER_ITEM).min(MAX_ARMOR);
+            }
             _ => return Err(Error::InvalidItemCollection),
         }
 
+        env.storage()
+            .temporary()
+            .set(&nullifier_key, &env.ledger().sequence());
+
+        let mut stats = Self::load_match_stats(&env, session_id);
+        if is_player1 {
+            stats.player1.items_collected += 1;
+        } else {
+            stats.player2.items_collected += 1;
+        }
+        Self::save_match_stats(&env, session_id, &stats);
+
+        Self::log_action(&env, session_id, game.current_turn, &player, LoggedAction::Item(item_id));
+        env.events().publish(
+            (symbol_short!("ITEM"), session_id),
+            (player, item_id, item_type),
+        );
+        env.storage()
+            .temporary()
+            .extend_ttl(&nullifier_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::record_activity(&env, &mut game, is_player1);
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
         env.storage()
@@ -506,26 +1423,15 @@ impl InterstellarContract {
         Ok(())
     }
 
-    /// Determine winner with ZK proof
+    /// Surrender the match, awarding the win to the opponent
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `proof` - Groth16 ZK proof of win condition
-    /// * `public_signals` - Public signals (p1_kills, p2_kills, p1_health, p2_health, winner)
-    ///
-    /// # Public Signals Format:
-    /// [0] = player1_kills
-    /// [1] = player2_kills
-    /// [2] = player1_health
-    /// [3] = player2_health
-    /// [4] = winner (0=tie, 1=player1, 2=player2)
-    /// [5] = reason (0=kills, 1=elimination, 2=time)
-    pub fn determine_winner(
-        env: Env,
-        session_id: u32,
-        proof: Groth16Proof,
-        public_signals: Vec<Bytes>,
-    ) -> Result<Address, Error> {
+    /// * `player` - Address of the surrendering player
+    pub fn surrender(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -533,62 +1439,1175 @@ impl InterstellarContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
-        }
-
-        // Verify proof using win verification key
-        Self::verify_win_proof(&env, proof, public_signals.clone())?;
-
-        // Extract winner from public signals
-        if public_signals.len() < 6 {
-            return Err(Error::InvalidProof);
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
         }
 
-        let winner_signal = Self::bytes_to_u32(&public_signals.get(4).unwrap());
-
-        let winner = match winner_signal {
-            1 => game.player1.clone(),
-            2 => game.player2.clone(),
-            _ => {
-                // Tie - use deterministic tiebreaker (player1 wins)
-                game.player1.clone()
-            }
+        let winner = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
         };
 
-        // Update game with winner
-        game.winner = Some(winner.clone());
-        game.phase = GamePhase::Complete;
+        Self::complete_game(&env, session_id, &mut game, winner.clone());
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        Ok(winner)
+    }
 
-        // Call GameHub to end the session
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
+    /// Claim the win because the opponent has not acted on their turn for too long
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the claiming player
+    pub fn claim_inactivity_win(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        // Only the player waiting on the opponent can claim
+        if Self::check_turn(&game, !is_player1).is_err() {
+            return Err(Error::NotYourTurn);
+        }
+        let opponent_last_action = if is_player1 {
+            game.player2_state.last_action_ledger
+        } else {
+            game.player1_state.last_action_ledger
+        };
+        let timeout = Self::get_inactivity_timeout(env.clone());
+        if env.ledger().sequence() < opponent_last_action + timeout {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        Self::complete_game(&env, session_id, &mut game, player.clone());
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(player)
+    }
+
+    /// Cancel a session neither player has acted in, releasing both wagers
+    ///
+    /// Only allowed once CANCEL_GRACE_LEDGERS have passed since the game started
+    /// and before any position commitment or turn-consuming action. Escrowed
+    /// wagers are refunded. GameHub has no cancellation or draw outcome, so a
+    /// hub-points session is not reported, as chess does for draws.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the cancelling player
+    pub fn cancel_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.current_turn > 0
+            || game.player1_state.position_commitment.is_some()
+            || game.player2_state.position_commitment.is_some()
+        {
+            return Err(Error::InvalidAction);
+        }
+        if env.ledger().sequence() < game.start_ledger + CANCEL_GRACE_LEDGERS {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        game.phase = GamePhase::Cancelled;
+        if let Some(escrow_token) = &game.escrow_token {
+            let token = token::TokenClient::new(&env, escrow_token);
+            let contract = env.current_contract_address();
+            token.transfer(&contract, &game.player1, &game.player1_points);
+            token.transfer(&contract, &game.player2, &game.player2_points);
+        }
+
+        env.events().publish((symbol_short!("CANCEL"), session_id), player);
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Get shots, hits, damage and item statistics for both players
+    pub fn get_match_stats(env: Env, session_id: u32) -> Result<MatchStats, Error> {
+        if !env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::GameNotFound);
+        }
+        Ok(Self::load_match_stats(&env, session_id))
+    }
+
+    /// Get the items spawned for a session
+    pub fn get_item_spawns(env: Env, session_id: u32) -> Result<Vec<ItemSpawn>, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::ItemSpawns(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Check whether an item has been collected and not yet respawned
+    pub fn is_item_collected(env: Env, session_id: u32, item_id: u32) -> bool {
+        let collected_at: Option<u32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ItemNullifier(session_id, item_id));
+        match collected_at {
+            Some(_) => Self::get_item_spawn_ledger(env.clone(), session_id, item_id)
+                .map(|ledger| env.ledger().sequence() < ledger)
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// Get the first ledger at which an item can next be collected
+    pub fn get_item_spawn_ledger(env: Env, session_id: u32, item_id: u32) -> Result<u32, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        let items: Vec<ItemSpawn> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ItemSpawns(session_id))
+            .ok_or(Error::GameNotFound)?;
+        let item = items.get(item_id).ok_or(Error::InvalidItemCollection)?;
+        let collected_at: Option<u32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ItemNullifier(session_id, item_id));
+        Ok(Self::item_spawn_ledger(&game, item.item_type, collected_at))
+    }
+
+    /// Determine winner with ZK proof
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `proof` - Groth16 ZK proof of win condition
+    /// * `public_signals` - Public signals (p1_kills, p2_kills, p1_health, p2_health, winner)
+    ///
+    /// # Public Signals Format:
+    /// [0] = player1_kills
+    /// [1] = player2_kills
+    /// [2] = player1_health
+    /// [3] = player2_health
+    /// [4] = winner (0=tie, 1=player1, 2=player2)
+    /// [5] = reason (0=kills, 1=elimination, 2=time)
+    pub fn determine_winner(
+        env: Env,
+        session_id: u32,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<Address, Error> {
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check if game already ended
+        if let Some(winner) = &game.winner {
+            return Ok(winner.clone());
+        }
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Verify proof using win verification key
+        Self::verify_win_proof(&env, proof, public_signals.clone())?;
+
+        // Extract winner from public signals
+        if public_signals.len() < 6 {
+            return Err(Error::InvalidProof);
+        }
+
+        let winner_signal = Self::bytes_to_u32(&public_signals.get(4).unwrap());
+
+        let winner = match winner_signal {
+            1 => game.player1.clone(),
+            2 => game.player2.clone(),
+            _ => {
+                // Tie - use deterministic tiebreaker (player1 wins)
+                game.player1.clone()
+            }
+        };
+
+        Self::complete_game(&env, session_id, &mut game, winner.clone());
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(winner)
+    }
+
+    /// Resolve an unambiguous result from stored state without a proof
+    ///
+    /// Covers the simple cases: a player at the kill limit, a dead player in a
+    /// match without a kill limit (single-life elimination), or an expired time
+    /// limit (leader on kills, then health). Anything else still needs
+    /// `determine_winner`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn finalize(env: Env, session_id: u32) -> Result<Address, Error> {
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check if game already ended
+        if let Some(winner) = &game.winner {
+            return Ok(winner.clone());
+        }
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let p1 = &game.player1_state;
+        let p2 = &game.player2_state;
+        let elapsed_ms = env.ledger().timestamp().saturating_sub(game.start_time) * 1000;
+        let winner = if game.kill_limit > 0 && p1.kills >= game.kill_limit {
+            game.player1.clone()
+        } else if game.kill_limit > 0 && p2.kills >= game.kill_limit {
+            game.player2.clone()
+        } else if game.kill_limit == 0 && p1.health > 0 && p2.health <= 0 {
+            game.player1.clone()
+        } else if game.kill_limit == 0 && p2.health > 0 && p1.health <= 0 {
+            game.player2.clone()
+        } else if game.time_limit > 0 && elapsed_ms >= game.time_limit {
+            Self::leader(&game)
+        } else {
+            return Err(Error::WinConditionNotMet);
+        };
+
+        Self::complete_game(&env, session_id, &mut game, winner.clone());
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(winner)
+    }
+
+    /// Get the address of the player whose turn it is
+    pub fn get_current_actor(env: Env, session_id: u32) -> Result<Address, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if game.current_turn % 2 == 0 {
+            Ok(game.player1)
+        } else {
+            Ok(game.player2)
+        }
+    }
+
+    /// Get a hash of the public match state for spectators to check their view against
+    ///
+    /// sha256 over the big-endian encoding of player1 health, player2 health,
+    /// player1 kills, player2 kills, current turn and phase (0=active,
+    /// 1=complete, 2=cancelled), 4 bytes each.
+    pub fn get_public_digest(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        let phase: u32 = match game.phase {
+            GamePhase::Active => 0,
+            GamePhase::Complete => 1,
+            GamePhase::Cancelled => 2,
+        };
+        let mut input = Bytes::from_array(&env, &game.player1_state.health.to_be_bytes());
+        input.extend_from_array(&game.player2_state.health.to_be_bytes());
+        input.extend_from_array(&game.player1_state.kills.to_be_bytes());
+        input.extend_from_array(&game.player2_state.kills.to_be_bytes());
+        input.extend_from_array(&game.current_turn.to_be_bytes());
+        input.extend_from_array(&phase.to_be_bytes());
+        Ok(env.crypto().sha256(&input).into())
+    }
+
+    /// Get a page of the session's action log, oldest first
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `start` - Index of the first record to return
+    /// * `limit` - Maximum number of records (capped at MAX_ACTIONS_PAGE)
+    pub fn get_actions(env: Env, session_id: u32, start: u32, limit: u32) -> Vec<ActionRecord> {
+        let count: u32 = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ActionCount(session_id))
+            .unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_ACTIONS_PAGE)).min(count);
+
+        let mut actions = Vec::new(&env);
+        for index in start..end {
+            if let Some(record) = env.storage().temporary().get(&DataKey::Action(session_id, index)) {
+                actions.push_back(record);
+            }
+        }
+        actions
+    }
+
+    /// Extend the TTL of a session's storage so long matches don't expire
+    ///
+    /// Anyone may call this (e.g. a player or game server paying the fee).
+    /// Extends the game, its spawn table and item nullifiers, match stats,
+    /// action count, and a page of up to MAX_ACTIONS_PAGE action-log entries
+    /// and pending hits from `start` (log index and shot turn respectively);
+    /// longer matches take further calls with later starts.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `start` - First action-log index and shot turn to extend
+    pub fn extend_game_ttl(env: Env, session_id: u32, start: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        let action_count: u32 = env
+            .storage()
+            .temporary()
+            .get(&DataKey::ActionCount(session_id))
+            .unwrap_or(0);
+        let page_end = start.saturating_add(MAX_ACTIONS_PAGE);
+        let item_count = env
+            .storage()
+            .temporary()
+            .get::<_, Vec<ItemSpawn>>(&DataKey::ItemSpawns(session_id))
+            .map(|items| items.len())
+            .unwrap_or(0);
+
+        let mut aux_keys = vec![
+            &env,
+            DataKey::ItemSpawns(session_id),
+            DataKey::MatchStats(session_id),
+            DataKey::ActionCount(session_id),
+        ];
+        for item_id in 0..item_count {
+            aux_keys.push_back(DataKey::ItemNullifier(session_id, item_id));
+        }
+        for index in start..page_end.min(action_count) {
+            aux_keys.push_back(DataKey::Action(session_id, index));
+        }
+        for turn in start..page_end.min(game.current_turn) {
+            aux_keys.push_back(DataKey::PendingHit(session_id, turn));
+        }
+        for aux_key in aux_keys.iter() {
+            if env.storage().temporary().has(&aux_key) {
+                env.storage()
+                    .temporary()
+                    .extend_ttl(&aux_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get a player's rating (INITIAL_RATING until their first finished match)
+    pub fn get_rating(env: Env, player: Address) -> i32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rating(player))
+            .unwrap_or(INITIAL_RATING)
+    }
+
+    /// Get game information
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let key = DataKey::Game(session_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Get a single player's health, kills, ammo, weapon and armor
+    pub fn get_player_state(env: Env, session_id: u32, player: Address) -> Result<PlayerSummary, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        let state = if player == game.player1 {
+            game.player1_state
+        } else if player == game.player2 {
+            game.player2_state
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        Ok(PlayerSummary {
+            health: state.health,
+            kills: state.kills,
+            ammo: state.ammo,
+            reserve_ammo: state.reserve_ammo,
+            weapon_type: state.weapon_type,
+            armor: state.armor,
+        })
+    }
+
+    /// Apply a move without the auth check (shared with `submit_actions`)
+    fn apply_move(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        Self::check_turn(&game, is_player1)?;
+
+        let current_turn = game.current_turn;
+        let player_state = if is_player1 {
+            &mut game.player1_state
+        } else {
+            &mut game.player2_state
+        };
+        if player_state.health <= 0 {
+            return Err(Error::PlayerDead);
+        }
+        if player_state.last_move_turn == Some(current_turn) {
+            return Err(Error::InvalidAction);
+        }
+
+        // Verify proof using movement verification key
+        Self::verify_movement_proof(&env, proof, public_signals.clone())?;
+
+        if public_signals.len() < 4 {
+            return Err(Error::InvalidProof);
+        }
+
+        let old_commitment = public_signals.get(0).unwrap();
+        if player_state.position_commitment != Some(old_commitment) {
+            return Err(Error::InvalidPosition);
+        }
+        if Self::bytes_to_u32(&public_signals.get(2).unwrap()) != MAX_MOVE_DISTANCE {
+            return Err(Error::InvalidPosition);
+        }
+        if Self::bytes_to_i32(&public_signals.get(3).unwrap()) != game.arena_half_size {
+            return Err(Error::InvalidPosition);
+        }
+
+        player_state.position_commitment = Some(public_signals.get(1).unwrap());
+        player_state.position_turn = current_turn;
+        player_state.last_move_turn = Some(current_turn);
+        player_state.last_action_ledger = env.ledger().sequence();
+        Self::log_action(&env, session_id, current_turn, &player, LoggedAction::Move);
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Apply a shot without the auth check (shared with `submit_actions`)
+    fn apply_shot(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<bool, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Determine shooter and target
+        let is_player1 = shooter == game.player1;
+        if !is_player1 && shooter != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        Self::prepare_shot(&env, &mut game, is_player1)?;
+
+        // Verify proof using shooting verification key
+        Self::verify_shooting_proof(&env, proof, public_signals.clone())?;
+
+        // Extract hit result from public signals
+        if public_signals.len() < 5 {
+            return Err(Error::InvalidProof);
+        }
+        if Self::bytes_to_i32(&public_signals.get(3).unwrap()) != game.arena_half_size {
+            return Err(Error::InvalidShot);
+        }
+        Self::check_shot_range(&env, &game, is_player1, &public_signals.get(4).unwrap())?;
+
+        let target_commitment = public_signals.get(1).unwrap();
+        Self::check_shot_positions(&game, is_player1, public_signals.get(0).unwrap(), target_commitment.clone())?;
+
+        let hit = Self::bytes_to_u32(&public_signals.get(2).unwrap()) == 1;
+
+        if hit {
+            let (target, weapon_type) = if is_player1 {
+                (game.player2.clone(), game.player1_state.weapon_type)
+            } else {
+                (game.player1.clone(), game.player2_state.weapon_type)
+            };
+            let hit_key = DataKey::PendingHit(session_id, game.current_turn);
+            env.storage().temporary().set(
+                &hit_key,
+                &PendingHit {
+                    shooter: shooter.clone(),
+                    target,
+                    weapon_type,
+                    target_commitment,
+                },
+            );
+            env.storage()
+                .temporary()
+                .extend_ttl(&hit_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        if !hit {
+            Self::settle_miss(&mut game, is_player1);
+        }
+        Self::record_shot(&env, session_id, &game, is_player1, &shooter, hit);
+        Self::end_turn(&env, session_id, &mut game, is_player1);
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(hit)
+    }
+
+    /// Apply one batched action without the auth check
+    fn apply_action(env: &Env, session_id: u32, player: &Address, action: ActionWithProof) -> Result<(), Error> {
+        match action.kind {
+            ActionKind::Move => {
+                Self::apply_move(env.clone(), session_id, player.clone(), action.proof, action.public_signals)?;
+            }
+            ActionKind::Shoot => {
+                Self::apply_shot(env.clone(), session_id, player.clone(), action.proof, action.public_signals)?;
+            }
+            ActionKind::Damage(hit_id) => {
+                Self::apply_hit_damage(env.clone(), session_id, player.clone(), hit_id, action.proof, action.public_signals)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check turn, liveness, position freshness, cooldown and ammo, then spend the ammo
+    fn prepare_shot(env: &Env, game: &mut Game, is_player1: bool) -> Result<(), Error> {
+        Self::check_turn(game, is_player1)?;
+        if game.player1_state.health <= 0 || game.player2_state.health <= 0 {
+            return Err(Error::PlayerDead);
+        }
+        Self::check_fresh_position(game, &game.player1_state)?;
+        Self::check_fresh_position(game, &game.player2_state)?;
+
+        let shooter_state = if is_player1 {
+            &mut game.player1_state
+        } else {
+            &mut game.player2_state
+        };
+        let weapon = Self::weapon_stats(env, shooter_state.weapon_type);
+        if game.current_turn < shooter_state.next_shot_turn {
+            return Err(Error::WeaponCooldown);
+        }
+        if shooter_state.ammo < weapon.ammo_cost {
+            return Err(Error::OutOfAmmo);
+        }
+        shooter_state.ammo -= weapon.ammo_cost;
+        // Each player acts every other turn
+        shooter_state.next_shot_turn = game.current_turn + 2 * weapon.cooldown_turns;
+
+        Ok(())
+    }
+
+    /// The shot must be proven against both players' current positions
+    fn check_shot_positions(
+        game: &Game,
+        is_player1: bool,
+        shooter_commitment: Bytes,
+        target_commitment: Bytes,
+    ) -> Result<(), Error> {
+        let (shooter_state, target_state) = if is_player1 {
+            (&game.player1_state, &game.player2_state)
+        } else {
+            (&game.player2_state, &game.player1_state)
+        };
+        if shooter_state.position_commitment != Some(shooter_commitment)
+            || target_state.position_commitment != Some(target_commitment)
+        {
+            return Err(Error::InvalidPosition);
+        }
+        Ok(())
+    }
+
+    /// Check that an item proof was made against the item's spawn position
+    fn check_item_position(item: &ItemSpawn, public_signals: &Vec<Bytes>) -> Result<(), Error> {
+        let position = Position {
+            x: Self::bytes_to_i32(&public_signals.get(4).unwrap()),
+            y: Self::bytes_to_i32(&public_signals.get(5).unwrap()),
+            z: Self::bytes_to_i32(&public_signals.get(6).unwrap()),
+        };
+        if position != item.position {
+            return Err(Error::InvalidItemCollection);
+        }
+        Ok(())
+    }
+
+    /// Check that a shot proof was made with the range of the shooter's weapon
+    fn check_shot_range(env: &Env, game: &Game, is_player1: bool, range_signal: &Bytes) -> Result<(), Error> {
+        let weapon_type = if is_player1 {
+            game.player1_state.weapon_type
+        } else {
+            game.player2_state.weapon_type
+        };
+        if Self::bytes_to_u32(range_signal) != Self::weapon_stats(env, weapon_type).range {
+            return Err(Error::InvalidShot);
+        }
+        Ok(())
+    }
+
+    /// Charge the shooter extra ammo for a miss and reward the target for evading
+    fn settle_miss(game: &mut Game, is_player1: bool) {
+        let (shooter_state, target_state) = if is_player1 {
+            (&mut game.player1_state, &mut game.player2_state)
+        } else {
+            (&mut game.player2_state, &mut game.player1_state)
+        };
+        shooter_state.ammo = shooter_state.ammo.saturating_sub(MISS_EXTRA_AMMO_COST);
+        target_state.reserve_ammo += EVASION_REWARD_AMMO;
+    }
+
+    /// Update stats, the action log and events for a shot taken this turn
+    fn record_shot(env: &Env, session_id: u32, game: &Game, is_player1: bool, shooter: &Address, hit: bool) {
+        let mut stats = Self::load_match_stats(env, session_id);
+        let shooter_stats = if is_player1 { &mut stats.player1 } else { &mut stats.player2 };
+        shooter_stats.shots_fired += 1;
+        if hit {
+            shooter_stats.hits += 1;
+        }
+        Self::save_match_stats(env, session_id, &stats);
+
+        Self::log_action(env, session_id, game.current_turn, shooter, LoggedAction::Shot(hit));
 
-        Ok(winner)
+        // hit_id is only meaningful when hit is true
+        env.events().publish(
+            (symbol_short!("SHOT"), session_id),
+            (shooter.clone(), hit, game.current_turn),
+        );
     }
 
-    /// Get game information
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+    /// Apply damage for a pending hit without the auth check (shared with `submit_actions`)
+    fn apply_hit_damage(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        hit_id: u32,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
-        env.storage()
+        let mut game: Game = env
+            .storage()
             .temporary()
             .get(&key)
-            .ok_or(Error::GameNotFound)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let hit_key = DataKey::PendingHit(session_id, hit_id);
+        let pending: PendingHit = env
+            .storage()
+            .temporary()
+            .get(&hit_key)
+            .ok_or(Error::PendingHitNotFound)?;
+        if pending.shooter != shooter {
+            return Err(Error::PendingHitNotFound);
+        }
+
+        // The target must still be where the hit was proven against
+        let target_state = if pending.target == game.player1 {
+            &game.player1_state
+        } else {
+            &game.player2_state
+        };
+        Self::check_committed_position(target_state, &pending.target_commitment)?;
+
+        // Verify proof using damage verification key
+        Self::verify_damage_proof(&env, proof, public_signals.clone())?;
+
+        // Extract damage info from public signals
+        if public_signals.len() < 5 {
+            return Err(Error::InvalidProof);
+        }
+
+        let old_health = Self::bytes_to_i32(&public_signals.get(0).unwrap());
+        let new_health = Self::bytes_to_i32(&public_signals.get(1).unwrap());
+        let weapon_type = Self::bytes_to_u32(&public_signals.get(2).unwrap());
+        let mitigation = Self::bytes_to_u32(&public_signals.get(4).unwrap());
+
+        // Damage is bound to the position the hit was proven against
+        if public_signals.get(3).unwrap() != pending.target_commitment {
+            return Err(Error::InvalidDamage);
+        }
+
+        // The proven damage must come from the weapon that landed the hit
+        if weapon_type != pending.weapon_type {
+            return Err(Error::InvalidDamage);
+        }
+
+        // Update target's health
+        let target = pending.target;
+        let is_player1 = target == game.player1;
+        if !is_player1 && target != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        let target_state = if is_player1 {
+            &game.player1_state
+        } else {
+            &game.player2_state
+        };
+        let shooter_state = if is_player1 {
+            &game.player2_state
+        } else {
+            &game.player1_state
+        };
+        let target_health = target_state.health;
+
+        // Armor held now (not at shot time) decides the mitigation
+        let weapon = Self::weapon_stats(&env, weapon_type);
+        let base = Self::boosted_damage(shooter_state, weapon.damage, hit_id);
+        if old_health - new_health != Self::mitigated_damage(target_state, base, mitigation)? {
+            return Err(Error::InvalidDamage);
+        }
+
+        // A stale hit can't kill a player twice
+        if target_health <= 0 {
+            return Err(Error::PlayerDead);
+        }
+
+        // The proof must be built against the target's actual current health
+        if old_health != target_health {
+            return Err(Error::InvalidDamage);
+        }
+
+        // Consume the hit so it can't be replayed
+        env.storage().temporary().remove(&hit_key);
+
+        Self::consume_armor(&mut game, is_player1);
+        Self::inflict_damage(&env, session_id, &mut game, is_player1, old_health - new_health, false);
+        Self::record_activity(&env, &mut game, !is_player1);
+        Self::log_action(&env, session_id, game.current_turn, &shooter, LoggedAction::Damage(hit_id));
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Weapon damage including any streak boost active when the shot was fired
+    fn boosted_damage(shooter: &PlayerState, damage: i32, shot_turn: u32) -> i32 {
+        if shot_turn < shooter.damage_boost_until_turn {
+            damage * (100 + shooter.damage_boost_percent as i32) / 100
+        } else {
+            damage
+        }
+    }
+
+    /// Damage a shot deals after armor, checking the proven mitigation factor
+    fn mitigated_damage(target: &PlayerState, damage: i32, mitigation: u32) -> Result<i32, Error> {
+        let expected = if target.armor > 0 { ARMOR_MITIGATION_PERCENT } else { 0 };
+        if mitigation != expected {
+            return Err(Error::InvalidDamage);
+        }
+        Ok(damage * (100 - mitigation as i32) / 100)
+    }
+
+    /// Use up one armor charge, if any, after a hit lands
+    fn consume_armor(game: &mut Game, is_player1: bool) {
+        let state = if is_player1 {
+            &mut game.player1_state
+        } else {
+            &mut game.player2_state
+        };
+        state.armor = state.armor.saturating_sub(1);
+    }
+
+    /// Reduce a player's health, crediting the opponent with the damage and any kill
+    ///
+    /// Self-inflicted damage still credits the opponent with the kill but not the
+    /// damage. Ends the match if the kill reaches the kill limit. The caller is
+    /// responsible for storing the updated game.
+    fn inflict_damage(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        target_is_player1: bool,
+        damage: i32,
+        self_inflicted: bool,
+    ) {
+        let (target, killer) = if target_is_player1 {
+            (game.player1.clone(), game.player2.clone())
+        } else {
+            (game.player2.clone(), game.player1.clone())
+        };
+        let (target_state, killer_state) = if target_is_player1 {
+            (&mut game.player1_state, &mut game.player2_state)
+        } else {
+            (&mut game.player2_state, &mut game.player1_state)
+        };
+
+        target_state.health -= damage;
+        let new_health = target_state.health;
+
+        let mut stats = Self::load_match_stats(env, session_id);
+        let (target_stats, killer_stats) = if target_is_player1 {
+            (&mut stats.player1, &mut stats.player2)
+        } else {
+            (&mut stats.player2, &mut stats.player1)
+        };
+        target_stats.damage_taken += damage;
+        if !self_inflicted {
+            killer_stats.damage_dealt += damage;
+        }
+        Self::save_match_stats(env, session_id, &stats);
+
+        env.events().publish(
+            (symbol_short!("DAMAGE"), session_id),
+            (target.clone(), damage, new_health),
+        );
+
+        // Check if player died
+        if new_health <= 0 {
+            killer_state.kills += 1;
+            // Each player acts every other turn
+            target_state.respawn_turn = Some(game.current_turn + 2 * RESPAWN_COOLDOWN_TURNS);
+            env.events().publish(
+                (symbol_short!("KILL"), session_id),
+                (killer.clone(), target, killer_state.kills),
+            );
+
+            // Dying ends the victim's streak and any boost it earned
+            target_state.streak = 0;
+            target_state.damage_boost_until_turn = 0;
+            killer_state.streak += 1;
+            for bonus in Self::streak_bonuses(env).iter() {
+                if bonus.kills == killer_state.streak {
+                    killer_state.reserve_ammo += bonus.bonus_ammo;
+                    if bonus.damage_boost_percent > 0 {
+                        killer_state.damage_boost_percent = bonus.damage_boost_percent;
+                        // Each player acts every other turn
+                        killer_state.damage_boost_until_turn = game.current_turn + 2 * bonus.boost_turns;
+                    }
+                    env.events().publish(
+                        (symbol_short!("STREAK"), session_id),
+                        (killer.clone(), killer_state.streak),
+                    );
+                }
+            }
+
+            // Reaching the kill limit ends the match immediately
+            if game.kill_limit > 0 && killer_state.kills >= game.kill_limit {
+                Self::complete_game(env, session_id, game, killer);
+            } else {
+                // A kill landed on the victim's own turn (a late `apply_damage`) skips it
+                Self::skip_respawning_turns(env, session_id, game);
+            }
+        }
+    }
+
+    /// Create a session without the auth check (shared with `rematch`)
+    fn create_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        kill_limit: u32,
+        time_limit: u64,
+        map_id: u32,
+        escrow_token: Option<Address>,
+        params: MatchParams,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
+
+        let bounds = Self::get_match_param_bounds(env.clone());
+        if params.starting_health < bounds.min_health
+            || params.starting_health > bounds.max_health
+            || params.starting_reserve_ammo > bounds.max_reserve_ammo
+        {
+            return Err(Error::InvalidMatchParams);
+        }
+
+        let map = Self::map_config(&env, map_id)?;
+        let default_loadout = Self::loadout(&env, 0, params.starting_reserve_ammo)?;
+
+        if let Some(escrow_token) = &escrow_token {
+            if player1_points <= 0 || player2_points <= 0 {
+                return Err(Error::InvalidWager);
+            }
+
+            // Escrow mode: take the wagers into this contract
+            let token = token::TokenClient::new(&env, escrow_token);
+            let contract = env.current_contract_address();
+            token.transfer(&player1, &contract, &player1_points);
+            token.transfer(&player2, &contract, &player2_points);
+        } else {
+            // Get GameHub address
+            let game_hub_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::GameHubAddress)
+                .expect("GameHub address not set");
+
+            // Create GameHub client
+            let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+            // Call Game Hub to start the session and lock points
+            game_hub.start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &player1,
+                &player2,
+                &player1_points,
+                &player2_points,
+            );
+        }
+
+        // Derive the item seed from the session and start ledger
+        let mut seed_input = Bytes::from_array(&env, &session_id.to_be_bytes());
+        seed_input.extend_from_array(&env.ledger().sequence().to_be_bytes());
+        let item_seed: BytesN<32> = env.crypto().sha256(&seed_input).into();
+
+        // Create game in Active phase
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_state: PlayerState {
+                health: params.starting_health,
+                kills: 0,
+                position_commitment: None,
+                weapon_type: default_loadout.weapon_type,  // Loadout 0 until the player picks one
+                ammo: Self::weapon_stats(&env, default_loadout.weapon_type).magazine_size,
+                reserve_ammo: default_loadout.reserve_ammo,
+                respawn_turn: None,
+                last_move_turn: None,
+                next_shot_turn: 0,
+                grenades: default_loadout.grenades,
+                position_turn: 0,
+                loadout_id: 0,
+                last_action_ledger: env.ledger().sequence(),
+                armor: 0,
+                streak: 0,
+                damage_boost_percent: 0,
+                damage_boost_until_turn: 0,
+            },
+            player2_state: PlayerState {
+                health: params.starting_health,
+                kills: 0,
+                position_commitment: None,
+                weapon_type: default_loadout.weapon_type,  // Loadout 0 until the player picks one
+                ammo: Self::weapon_stats(&env, default_loadout.weapon_type).magazine_size,
+                reserve_ammo: default_loadout.reserve_ammo,
+                respawn_turn: None,
+                last_move_turn: None,
+                next_shot_turn: 0,
+                grenades: default_loadout.grenades,
+                position_turn: 0,
+                loadout_id: 0,
+                last_action_ledger: env.ledger().sequence(),
+                armor: 0,
+                streak: 0,
+                damage_boost_percent: 0,
+                damage_boost_until_turn: 0,
+            },
+            kill_limit,
+            time_limit,
+            start_time: env.ledger().timestamp(),
+            start_ledger: env.ledger().sequence(),
+            item_seed: item_seed.clone(),
+            map_id,
+            arena_half_size: map.half_size,
+            escrow_token,
+            starting_health: params.starting_health,
+            starting_reserve_ammo: params.starting_reserve_ammo,
+            current_turn: 0,
+            last_actor: 0,
+            winner: None,
+            phase: GamePhase::Active,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        let items = if map.item_layout.is_empty() {
+            Self::derive_item_spawns(&env, &item_seed, map.half_size)
+        } else {
+            map.item_layout
+        };
+        let items_key = DataKey::ItemSpawns(session_id);
+        env.storage().temporary().set(&items_key, &items);
+        env.storage()
+            .temporary()
+            .extend_ttl(&items_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Record the winner, mark the game complete and settle the wagers
+    ///
+    /// Escrowed wagers are paid out to the winner; otherwise the GameHub settles
+    /// the locked points. The caller is responsible for storing the updated game.
+    fn complete_game(env: &Env, session_id: u32, game: &mut Game, winner: Address) {
+        game.winner = Some(winner.clone());
+        game.phase = GamePhase::Complete;
+
+        let player1_won = winner == game.player1;
+        if let Some(escrow_token) = &game.escrow_token {
+            let payout = game.player1_points + game.player2_points;
+            token::TokenClient::new(env, escrow_token).transfer(
+                &env.current_contract_address(),
+                &winner,
+                &payout,
+            );
+        } else {
+            // Get GameHub address
+            let game_hub_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::GameHubAddress)
+                .expect("GameHub address not set");
+
+            // Create GameHub client
+            let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+            // Call GameHub to end the session
+            game_hub.end_game(&session_id, &player1_won);
+        }
+
+        let loser = if player1_won {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        };
+        Self::update_ratings(env, session_id, &winner, &loser);
+
+        env.events().publish((symbol_short!("END"), session_id), winner);
+    }
+
+    /// Apply an ELO-style update after `winner` beats `loser`
+    ///
+    /// The expected score is a linear approximation of the logistic curve over the
+    /// clamped rating difference, in per-mille (500 for equal ratings, ~91 at -400).
+    fn update_ratings(env: &Env, session_id: u32, winner: &Address, loser: &Address) {
+        let winner_rating = Self::get_rating(env.clone(), winner.clone());
+        let loser_rating = Self::get_rating(env.clone(), loser.clone());
+
+        let diff = (loser_rating - winner_rating).clamp(-RATING_MAX_DIFF, RATING_MAX_DIFF);
+        let expected = 500 - diff * 409 / RATING_MAX_DIFF;
+        let delta = RATING_K_FACTOR * (1000 - expected) / 1000;
+
+        for (player, rating) in [(winner, winner_rating + delta), (loser, loser_rating - delta)] {
+            let key = DataKey::Rating(player.clone());
+            env.storage().persistent().set(&key, &rating);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, RATING_TTL_LEDGERS, RATING_TTL_LEDGERS);
+        }
+
+        env.events().publish(
+            (symbol_short!("RATING"), session_id),
+            (winner.clone(), loser.clone(), delta),
+        );
     }
 
     // ========================================================================
@@ -604,8 +2623,92 @@ impl InterstellarContract {
         let vk: VerificationKey = env
             .storage()
             .instance()
-            .get(&DataKey::ShootingVerificationKey)
-            .ok_or(Error::InvalidProof)?;
+            .get(&DataKey::ShootingVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
+
+        let verifier_proof = VerifierProof {
+            pi_a: proof.pi_a,
+            pi_b: proof.pi_b,
+            pi_c: proof.pi_c,
+        };
+
+        let is_valid = verify_groth16(env, &vk, &verifier_proof, &public_signals)
+            .map_err(|_| Error::InvalidProof)?;
+
+        if !is_valid {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Verify damage proof
+    fn verify_damage_proof(
+        env: &Env,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        let vk: VerificationKey = env
+            .storage()
+            .instance()
+            .get(&DataKey::DamageVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
+
+        let verifier_proof = VerifierProof {
+            pi_a: proof.pi_a,
+            pi_b: proof.pi_b,
+            pi_c: proof.pi_c,
+        };
+
+        let is_valid = verify_groth16(env, &vk, &verifier_proof, &public_signals)
+            .map_err(|_| Error::InvalidProof)?;
+
+        if !is_valid {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Verify item collection proof
+    fn verify_item_proof(
+        env: &Env,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        let vk: VerificationKey = env
+            .storage()
+            .instance()
+            .get(&DataKey::ItemVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
+
+        let verifier_proof = VerifierProof {
+            pi_a: proof.pi_a,
+            pi_b: proof.pi_b,
+            pi_c: proof.pi_c,
+        };
+
+        let is_valid = verify_groth16(env, &vk, &verifier_proof, &public_signals)
+            .map_err(|_| Error::InvalidProof)?;
+
+        if !is_valid {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Verify win condition proof
+    fn verify_win_proof(
+        env: &Env,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        let vk: VerificationKey = env
+            .storage()
+            .instance()
+            .get(&DataKey::WinVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
 
         let verifier_proof = VerifierProof {
             pi_a: proof.pi_a,
@@ -623,8 +2726,8 @@ impl InterstellarContract {
         Ok(())
     }
 
-    /// Verify damage proof
-    fn verify_damage_proof(
+    /// Verify movement proof
+    fn verify_movement_proof(
         env: &Env,
         proof: Groth16Proof,
         public_signals: Vec<Bytes>,
@@ -632,8 +2735,8 @@ impl InterstellarContract {
         let vk: VerificationKey = env
             .storage()
             .instance()
-            .get(&DataKey::DamageVerificationKey)
-            .ok_or(Error::InvalidProof)?;
+            .get(&DataKey::MovementVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
 
         let verifier_proof = VerifierProof {
             pi_a: proof.pi_a,
@@ -651,8 +2754,8 @@ impl InterstellarContract {
         Ok(())
     }
 
-    /// Verify item collection proof
-    fn verify_item_proof(
+    /// Verify combined shoot-and-damage proof
+    fn verify_shoot_damage_proof(
         env: &Env,
         proof: Groth16Proof,
         public_signals: Vec<Bytes>,
@@ -660,8 +2763,8 @@ impl InterstellarContract {
         let vk: VerificationKey = env
             .storage()
             .instance()
-            .get(&DataKey::ItemVerificationKey)
-            .ok_or(Error::InvalidProof)?;
+            .get(&DataKey::ShootDamageVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
 
         let verifier_proof = VerifierProof {
             pi_a: proof.pi_a,
@@ -679,8 +2782,8 @@ impl InterstellarContract {
         Ok(())
     }
 
-    /// Verify win condition proof
-    fn verify_win_proof(
+    /// Verify grenade proof
+    fn verify_grenade_proof(
         env: &Env,
         proof: Groth16Proof,
         public_signals: Vec<Bytes>,
@@ -688,8 +2791,8 @@ impl InterstellarContract {
         let vk: VerificationKey = env
             .storage()
             .instance()
-            .get(&DataKey::WinVerificationKey)
-            .ok_or(Error::InvalidProof)?;
+            .get(&DataKey::GrenadeVerificationKey)
+            .ok_or(Error::VerificationKeyNotSet)?;
 
         let verifier_proof = VerifierProof {
             pi_a: proof.pi_a,
@@ -707,6 +2810,235 @@ impl InterstellarContract {
         Ok(())
     }
 
+    /// Look up a loadout; without an admin table only the default loadout 0 exists,
+    /// carrying `default_reserve_ammo`
+    fn loadout(env: &Env, loadout_id: u32, default_reserve_ammo: u32) -> Result<Loadout, Error> {
+        let table: Option<Vec<Loadout>> = env.storage().instance().get(&DataKey::Loadouts);
+        match table {
+            Some(table) => table.get(loadout_id).ok_or(Error::LoadoutNotFound),
+            None if loadout_id == 0 => Ok(Loadout {
+                weapon_type: 0,  // Pistol
+                reserve_ammo: default_reserve_ammo,
+                grenades: STARTING_GRENADES,
+            }),
+            None => Err(Error::LoadoutNotFound),
+        }
+    }
+
+    /// Look up a map, falling back to the built-in default for DEFAULT_MAP_ID
+    fn map_config(env: &Env, map_id: u32) -> Result<MapConfig, Error> {
+        match env.storage().instance().get(&DataKey::Map(map_id)) {
+            Some(map) => Ok(map),
+            None if map_id == DEFAULT_MAP_ID => Ok(MapConfig {
+                half_size: ARENA_HALF_SIZE,
+                item_layout: Vec::new(env),
+            }),
+            None => Err(Error::MapNotFound),
+        }
+    }
+
+    /// Derive the item spawn table from a session seed
+    ///
+    /// Item `i` is placed using sha256(seed || i): byte 0 picks the type,
+    /// bytes 1-2 and 3-4 the x and z coordinates; items sit on the ground (y = 0).
+    fn derive_item_spawns(env: &Env, seed: &BytesN<32>, half_size: i32) -> Vec<ItemSpawn> {
+        let span = (2 * half_size + 1) as u32;
+        let mut items = Vec::new(env);
+        for item_id in 0..ITEM_SPAWN_COUNT {
+            let mut input = Bytes::from_array(env, &seed.to_array());
+            input.extend_from_array(&item_id.to_be_bytes());
+            let hash = env.crypto().sha256(&input).to_array();
+
+            let x = u16::from_be_bytes([hash[1], hash[2]]) as u32 % span;
+            let z = u16::from_be_bytes([hash[3], hash[4]]) as u32 % span;
+            items.push_back(ItemSpawn {
+                item_id,
+                item_type: hash[0] as u32 % ITEM_TYPE_COUNT,
+                position: Position {
+                    x: x as i32 - half_size,
+                    y: 0,
+                    z: z as i32 - half_size,
+                },
+            });
+        }
+        items
+    }
+
+    /// Append a record to the session's action log
+    fn log_action(env: &Env, session_id: u32, turn: u32, player: &Address, action: LoggedAction) {
+        let count_key = DataKey::ActionCount(session_id);
+        let index: u32 = env.storage().temporary().get(&count_key).unwrap_or(0);
+
+        let record_key = DataKey::Action(session_id, index);
+        env.storage().temporary().set(
+            &record_key,
+            &ActionRecord {
+                player: player.clone(),
+                turn,
+                ledger: env.ledger().sequence(),
+                action,
+            },
+        );
+        env.storage().temporary().set(&count_key, &(index + 1));
+        env.storage()
+            .temporary()
+            .extend_ttl(&record_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        env.storage()
+            .temporary()
+            .extend_ttl(&count_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    fn load_match_stats(env: &Env, session_id: u32) -> MatchStats {
+        env.storage()
+            .temporary()
+            .get(&DataKey::MatchStats(session_id))
+            .unwrap_or_default()
+    }
+
+    fn save_match_stats(env: &Env, session_id: u32, stats: &MatchStats) {
+        let key = DataKey::MatchStats(session_id);
+        env.storage().temporary().set(&key, stats);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Reject gameplay while the admin has paused the contract
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if env.storage().instance().get(&DataKey::Paused).unwrap_or(false) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Reject the action unless it is the acting player's turn
+    fn check_turn(game: &Game, is_player1: bool) -> Result<(), Error> {
+        let actor = if is_player1 { 0 } else { 1 };
+        if game.current_turn % 2 != actor {
+            return Err(Error::NotYourTurn);
+        }
+        Ok(())
+    }
+
+    /// Parameters for a weapon type, from the admin table or the defaults
+    fn weapon_stats(env: &Env, weapon_type: u32) -> WeaponStats {
+        let weapon_type = weapon_type.min(WEAPON_COUNT - 1);
+        let table: Option<Vec<WeaponStats>> = env.storage().instance().get(&DataKey::WeaponTable);
+        match table {
+            Some(table) => table.get(weapon_type).unwrap(),
+            None => {
+                let i = weapon_type as usize;
+                WeaponStats {
+                    damage: DEFAULT_WEAPON_DAMAGE[i],
+                    range: DEFAULT_WEAPON_RANGE[i],
+                    ammo_cost: DEFAULT_WEAPON_AMMO_COST[i],
+                    magazine_size: DEFAULT_WEAPON_MAGAZINE_SIZE[i],
+                    cooldown_turns: DEFAULT_WEAPON_COOLDOWN_TURNS[i],
+                }
+            }
+        }
+    }
+
+    /// First ledger of the spawn window after the last collection (or after game start)
+    fn item_spawn_ledger(game: &Game, item_type: u32, collected_at: Option<u32>) -> u32 {
+        let interval = ITEM_SPAWN_INTERVAL_LEDGERS[item_type.min(ITEM_TYPE_COUNT - 1) as usize];
+        let windows_elapsed = match collected_at {
+            Some(ledger) => (ledger - game.start_ledger) / interval + 1,
+            None => 1,
+        };
+        game.start_ledger + windows_elapsed * interval
+    }
+
+    /// Look up the streak rewards, falling back to the built-in defaults
+    fn streak_bonuses(env: &Env) -> Vec<StreakBonus> {
+        env.storage()
+            .instance()
+            .get(&DataKey::StreakBonuses)
+            .unwrap_or_else(|| {
+                vec![
+                    env,
+                    StreakBonus {
+                        kills: DEFAULT_STREAK_AMMO_KILLS,
+                        bonus_ammo: DEFAULT_STREAK_AMMO,
+                        damage_boost_percent: 0,
+                        boost_turns: 0,
+                    },
+                    StreakBonus {
+                        kills: DEFAULT_STREAK_BOOST_KILLS,
+                        bonus_ammo: 0,
+                        damage_boost_percent: DEFAULT_STREAK_BOOST_PERCENT,
+                        boost_turns: DEFAULT_STREAK_BOOST_TURNS,
+                    },
+                ]
+            })
+    }
+
+    /// Reject combat involving a player whose position commitment is missing or stale
+    fn check_fresh_position(game: &Game, state: &PlayerState) -> Result<(), Error> {
+        if state.position_commitment.is_none()
+            || game.current_turn > state.position_turn + POSITION_FRESHNESS_TURNS
+        {
+            return Err(Error::StalePosition);
+        }
+        Ok(())
+    }
+
+    /// Reject a proof made against anything but the player's stored position commitment
+    fn check_committed_position(state: &PlayerState, commitment: &Bytes) -> Result<(), Error> {
+        if state.position_commitment.as_ref() != Some(commitment) {
+            return Err(Error::InvalidPosition);
+        }
+        Ok(())
+    }
+
+    /// Pass the turn to the opponent, resolving the match once MAX_TURNS is reached
+    fn end_turn(env: &Env, session_id: u32, game: &mut Game, is_player1: bool) {
+        game.current_turn += 1;
+        game.last_actor = if is_player1 { 0 } else { 1 };
+        Self::record_activity(env, game, is_player1);
+        Self::skip_respawning_turns(env, session_id, game);
+    }
+
+    /// Skip the turns of a dead player still in respawn cooldown, resolving the
+    /// match once MAX_TURNS is reached
+    fn skip_respawning_turns(env: &Env, session_id: u32, game: &mut Game) {
+        while game.current_turn < MAX_TURNS && Self::respawn_pending(game, game.current_turn % 2 == 0) {
+            game.current_turn += 1;
+        }
+
+        if game.current_turn >= MAX_TURNS && game.phase == GamePhase::Active {
+            let winner = Self::leader(game);
+            Self::complete_game(env, session_id, game, winner);
+        }
+    }
+
+    /// Whether a player is dead and can't respawn yet at the current turn
+    fn respawn_pending(game: &Game, is_player1: bool) -> bool {
+        let state = if is_player1 { &game.player1_state } else { &game.player2_state };
+        state.respawn_turn.is_some_and(|turn| game.current_turn < turn)
+    }
+
+    /// The player ahead on kills, then remaining health; ties go to player1
+    fn leader(game: &Game) -> Address {
+        let p1 = &game.player1_state;
+        let p2 = &game.player2_state;
+        if (p2.kills, p2.health) > (p1.kills, p1.health) {
+            game.player2.clone()
+        } else {
+            game.player1.clone()
+        }
+    }
+
+    /// Record that a player acted in the current ledger
+    fn record_activity(env: &Env, game: &mut Game, is_player1: bool) {
+        let ledger = env.ledger().sequence();
+        if is_player1 {
+            game.player1_state.last_action_ledger = ledger;
+        } else {
+            game.player2_state.last_action_ledger = ledger;
+        }
+    }
+
     /// Convert Bytes to u32 (big-endian)
     fn bytes_to_u32(bytes: &Bytes) -> u32 {
         let mut result: u32 = 0;
@@ -831,6 +3163,296 @@ impl InterstellarContract {
             .set(&DataKey::WinVerificationKey, &vk);
     }
 
+    /// Set verification key for movement circuit
+    pub fn set_movement_vk(env: Env, vk: VerificationKey) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MovementVerificationKey, &vk);
+    }
+
+    /// Set verification key for grenade circuit
+    pub fn set_grenade_vk(env: Env, vk: VerificationKey) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GrenadeVerificationKey, &vk);
+    }
+
+    /// Set verification key for combined shoot-and-damage circuit
+    pub fn set_shoot_damage_vk(env: Env, vk: VerificationKey) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ShootDamageVerificationKey, &vk);
+    }
+
+    /// Get the installed verification key for the shooting circuit, if any
+    pub fn get_shooting_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::ShootingVerificationKey)
+    }
+
+    /// Get the installed verification key for the damage circuit, if any
+    pub fn get_damage_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::DamageVerificationKey)
+    }
+
+    /// Get the installed verification key for the item collection circuit, if any
+    pub fn get_item_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::ItemVerificationKey)
+    }
+
+    /// Get the installed verification key for the win condition circuit, if any
+    pub fn get_win_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::WinVerificationKey)
+    }
+
+    /// Get the installed verification key for the movement circuit, if any
+    pub fn get_movement_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::MovementVerificationKey)
+    }
+
+    /// Get the installed verification key for the grenade circuit, if any
+    pub fn get_grenade_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::GrenadeVerificationKey)
+    }
+
+    /// Get the installed verification key for the combined shoot-and-damage circuit, if any
+    pub fn get_shoot_damage_vk(env: Env) -> Option<VerificationKey> {
+        env.storage().instance().get(&DataKey::ShootDamageVerificationKey)
+    }
+
+    /// Set the loadout table; loadout 0 is every player's default
+    pub fn set_loadouts(env: Env, loadouts: Vec<Loadout>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if loadouts.is_empty() {
+            return Err(Error::InvalidAction);
+        }
+        for loadout in loadouts.iter() {
+            if loadout.weapon_type >= WEAPON_COUNT {
+                return Err(Error::InvalidAction);
+            }
+        }
+
+        env.storage().instance().set(&DataKey::Loadouts, &loadouts);
+        Ok(())
+    }
+
+    /// Get the loadouts players can choose from
+    pub fn get_loadouts(env: Env) -> Vec<Loadout> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Loadouts)
+            .unwrap_or_else(|| vec![&env, Self::loadout(&env, 0, STARTING_RESERVE_AMMO).unwrap()])
+    }
+
+    /// Set the bounds sessions' starting health and reserve ammo must fall within
+    pub fn set_match_param_bounds(env: Env, bounds: MatchParamBounds) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if bounds.min_health <= 0 || bounds.max_health < bounds.min_health {
+            return Err(Error::InvalidMatchParams);
+        }
+
+        env.storage().instance().set(&DataKey::MatchParamBounds, &bounds);
+        Ok(())
+    }
+
+    /// Get the bounds on per-session starting values
+    pub fn get_match_param_bounds(env: Env) -> MatchParamBounds {
+        env.storage()
+            .instance()
+            .get(&DataKey::MatchParamBounds)
+            .unwrap_or(MatchParamBounds {
+                min_health: DEFAULT_MIN_STARTING_HEALTH,
+                max_health: DEFAULT_MAX_STARTING_HEALTH,
+                max_reserve_ammo: DEFAULT_MAX_STARTING_RESERVE_AMMO,
+            })
+    }
+
+    /// Set the kill-streak rewards; an empty table disables them
+    pub fn set_streak_bonuses(env: Env, bonuses: Vec<StreakBonus>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        for (i, bonus) in bonuses.iter().enumerate() {
+            if bonus.kills == 0 || (bonus.damage_boost_percent > 0 && bonus.boost_turns == 0) {
+                return Err(Error::InvalidAction);
+            }
+            // At most one reward per streak length
+            for other in bonuses.iter().skip(i + 1) {
+                if other.kills == bonus.kills {
+                    return Err(Error::InvalidAction);
+                }
+            }
+        }
+
+        env.storage().instance().set(&DataKey::StreakBonuses, &bonuses);
+        Ok(())
+    }
+
+    /// Get the kill-streak rewards currently in effect
+    pub fn get_streak_bonuses(env: Env) -> Vec<StreakBonus> {
+        Self::streak_bonuses(&env)
+    }
+
+    /// Add or replace a map
+    ///
+    /// Fixed item layouts must be numbered 0..n in order and lie within the bounds.
+    pub fn set_map(env: Env, map_id: u32, map: MapConfig) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if map.half_size <= 0 {
+            return Err(Error::InvalidPosition);
+        }
+        for (i, item) in map.item_layout.iter().enumerate() {
+            if item.item_id != i as u32
+                || item.item_type >= ITEM_TYPE_COUNT
+                || item.position.x.abs() > map.half_size
+                || item.position.z.abs() > map.half_size
+            {
+                return Err(Error::InvalidPosition);
+            }
+        }
+
+        env.storage().instance().set(&DataKey::Map(map_id), &map);
+        Ok(())
+    }
+
+    /// Get a map's configuration
+    pub fn get_map(env: Env, map_id: u32) -> Result<MapConfig, Error> {
+        Self::map_config(&env, map_id)
+    }
+
+    /// Set the weapon table (one entry per weapon type)
+    pub fn set_weapon_table(env: Env, weapons: Vec<WeaponStats>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if weapons.len() != WEAPON_COUNT {
+            return Err(Error::InvalidAction);
+        }
+        for weapon in weapons.iter() {
+            if weapon.damage <= 0
+                || weapon.ammo_cost == 0
+                || weapon.magazine_size < weapon.ammo_cost
+                || weapon.cooldown_turns == 0
+            {
+                return Err(Error::InvalidAction);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::WeaponTable, &weapons);
+        Ok(())
+    }
+
+    /// Get the parameters currently in effect for every weapon type
+    pub fn get_weapon_table(env: Env) -> Vec<WeaponStats> {
+        let mut weapons = Vec::new(&env);
+        for weapon_type in 0..WEAPON_COUNT {
+            weapons.push_back(Self::weapon_stats(&env, weapon_type));
+        }
+        weapons
+    }
+
+    /// Set the number of ledgers a player may go without acting on their turn
+    pub fn set_inactivity_timeout(env: Env, ledgers: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if ledgers == 0 {
+            return Err(Error::InvalidAction);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::InactivityTimeout, &ledgers);
+        Ok(())
+    }
+
+    pub fn get_inactivity_timeout(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::InactivityTimeout)
+            .unwrap_or(DEFAULT_INACTIVITY_TIMEOUT_LEDGERS)
+    }
+
+    /// Halt all gameplay entrypoints
+    pub fn pause(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &true);
+    }
+
+    /// Resume gameplay
+    pub fn unpause(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &false);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+    }
+
     /// Update the contract WASM hash (upgrade contract)
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let admin: Address = env
@@ -12,9 +12,11 @@
 //! - No cheating possible after commitment
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror, 
-    contractimpl, contracttype, vec, panic_with_error
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, U256, Vec, contract, contractclient, contracterror, 
+    contractimpl, contracttype, vec, panic_with_error, crypto::BnScalar, symbol_short, token
 };
+use soroban_sdk::xdr::ToXdr;
+use soroban_poseidon::poseidon_hash;
 
 mod verifier;
 use verifier::{Groth16Proof as VerifierProof, VerificationKey, verify_groth16};
@@ -40,6 +42,18 @@ pub trait GameHub {
     );
 }
 
+// Interface a parent tournament contract implements to receive session results
+#[contractclient(name = "TournamentClient")]
+pub trait Tournament {
+    fn report_result(
+        env: Env,
+        tournament_id: u32,
+        session_id: u32,
+        winner: Address,
+        loser: Address,
+    );
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -57,6 +71,25 @@ pub enum Error {
     InvalidProof = 7,
     InvalidCommitment = 8,
     NotInPhase = 9,
+    InvalidBlinds = 10,
+    InvalidConfig = 11,
+    NotYourTurn = 12,
+    InsufficientStack = 13,
+    InvalidBetSize = 14,
+    CannotCheck = 15,
+    TimeoutNotReached = 16,
+    InvalidBuyIn = 17,
+    InvalidSplit = 18,
+    ContractPaused = 19,
+    UnsupportedSchema = 20,
+    PayoutNotConfigured = 21,
+    SessionNotOver = 22,
+    RaiseCapReached = 23,
+    ProofAlreadyUsed = 24,
+    InvalidCard = 25,
+    InvalidStake = 26,
+    NotSittingOut = 27,
+    InvalidHandRank = 28,
 }
 
 // ============================================================================
@@ -87,6 +120,59 @@ pub enum Action {
     AllIn,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BettingStructure {
+    NoLimit,    // Any bet/raise up to the whole stack
+    PotLimit,   // Bets/raises capped at the size of the pot
+    FixedLimit, // Fixed bet size (small bet preflop/flop, big bet turn/river), capped raises
+}
+
+/// Blinds and ante for one tournament level
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlindLevel {
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub ante: i128,
+}
+
+/// Tournament blind schedule: the level increases every `hands_per_level`
+/// hands and stays at the last level once the schedule is exhausted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentConfig {
+    pub hands_per_level: u32,
+    pub levels: Vec<BlindLevel>,
+}
+
+/// Tournament a session belongs to; its result is reported there on completion
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParentTournament {
+    pub contract: Address,
+    pub tournament_id: u32,
+}
+
+/// Table configuration agreed by both players at `start_game`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableConfig {
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub max_hands: u32,  // 1 = single hand, >1 = multi-hand match with carried-over stacks
+    pub structure: BettingStructure,
+    pub ante: i128,  // Per-hand ante from each player (0 = no ante)
+    pub tournament: Option<TournamentConfig>,  // Escalating blinds (overrides blinds/ante)
+    pub name: Option<Symbol>,  // Table name shown in lobby listings
+    pub escrow_token: Option<Address>,  // Escrow buy-ins in this SAC token instead of GameHub points
+    pub bounty_bps: u32,  // Share of each buy-in set aside as a knockout bounty (multi-hand only)
+    pub parent: Option<ParentTournament>,  // Multi-table tournament this session is part of
+    pub raise_cap: u32,  // Max bets + raises per round (0 = default: 4 in fixed-limit, else uncapped)
+    pub matched_stakes: bool,  // Require both players to buy in for the same amount
+    pub max_rebuys: u32,  // Rebuys allowed per player between hands (escrow multi-hand only)
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -106,14 +192,35 @@ pub struct Game {
     // Pot
     pub pot: i128,
     
+    // Side pot: uncalled excess of the bigger stack when the shorter stack is all-in
+    // Only the owner contributed to it, so it is refunded to their stack when the round closes
+    pub side_pot: i128,
+    pub side_pot_owner: u32,  // 0 = player1, 1 = player2
+    
+    // Blinds and dealer button (0 = player1, 1 = player2)
+    // Heads-up: the dealer posts the small blind, acts first preflop and is
+    // in position (acts last) on every later street. The button alternates each hand.
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub ante: i128,
+    pub dealer: u32,
+    
+    // Multi-hand match: the session ends when a stack is busted or max_hands is reached
+    pub hand_number: u32,  // 1-based
+    pub max_hands: u32,
+    
+    // Tournament blind schedule and current level (0-based)
+    pub tournament: Option<TournamentConfig>,
+    pub blind_level: u32,
+    
     // Hole card commitments (2 cards each)
     pub player1_hole_commitment: Option<Bytes>,  // Poseidon hash of 2 hole cards
     pub player2_hole_commitment: Option<Bytes>,
     
-    // Community cards (5 cards, 0-51 representing deck)
-    pub community_cards: Vec<u32>,  // Actual card values generated deterministically
+    // Community cards (up to 5 cards, 0-51 representing deck)
+    pub community_cards: Vec<u32>,  // Cards dealt so far from the commitment-seeded shuffle
     
-    // Community cards commitment (5 cards)
+    // Community cards commitment: keccak256 of the 5 cards, set once both players commit
     pub community_commitment: Option<Bytes>,
     
     // Revealed community cards count (0-5)
@@ -124,15 +231,195 @@ pub struct Game {
     pub last_action: Action,
     pub last_raise_amount: i128,
     pub actions_this_round: u32,  // Count of actions in current betting round
+    pub raises_this_round: u32,   // Bets and raises in current betting round
+    pub last_aggressor: Option<u32>,  // Last player to bet or raise this hand
+    pub raise_cap: u32,  // Max bets + raises per round (0 = uncapped)
+    pub betting_reopened: bool,  // False after a short all-in: the facing player may only call or fold
+    
+    // Betting structure chosen at start_game
+    pub structure: BettingStructure,
     
     // Showdown
     pub player1_revealed: bool,
     pub player2_revealed: bool,
-    pub player1_ranking: Option<u32>,  // Hand ranking (0-9)
+    pub player1_ranking: Option<u32>,  // Hand ranking (higher is stronger)
     pub player2_ranking: Option<u32>,
+    pub player1_second_ranking: Option<u32>,  // Second board ranking when running it twice
+    pub player2_second_ranking: Option<u32>,
     pub winner: Option<Address>,
     
     pub phase: Phase,
+    
+    // Verification key version pinned at start_game
+    pub vk_version: u32,
+    
+    // Contract-generated nonce the showdown proof must commit to (fresh every hand)
+    pub proof_nonce: u32,
+    
+    // Ledger sequence after which a missing showdown proof can be forfeited
+    pub showdown_deadline: Option<u32>,
+    
+    // Ledger sequence after which a lone committed player can reclaim the hand
+    pub commit_deadline: u32,
+    
+    // Run it twice: opt-in votes, second board and per-board winners
+    pub player1_run_twice: bool,
+    pub player2_run_twice: bool,
+    pub second_board: Vec<u32>,
+    pub second_board_commitment: Option<Bytes>,
+    pub first_board_winner: Option<u32>,
+    pub second_board_winner: Option<u32>,
+    
+    // Co-signed all-in settlement: player1's share of the pot in basis points
+    pub agreed_split_bps: Option<u32>,
+    
+    // Sit-out (multi-hand only): sitting-out players post blinds and auto-fold
+    pub player1_sitting_out: bool,
+    pub player2_sitting_out: bool,
+    pub player1_missed_hands: u32,  // Consecutive hands auto-folded
+    pub player2_missed_hands: u32,
+    
+    // Optional table name from start_game
+    pub table_name: Option<Symbol>,
+    
+    // Escrow mode: buy-ins held by this contract in a SAC token (GameHub unused)
+    pub escrow_token: Option<Address>,
+    
+    // Knockout bounties: set aside from each buy-in, paid to whoever busts the owner
+    pub player1_bounty: i128,
+    pub player2_bounty: i128,
+    pub bounty_winner: Option<u32>,  // Seat that collected the opponent's bounty
+    
+    // Multi-table tournament linkage
+    pub parent: Option<ParentTournament>,
+    
+    // Rebuys: limit per player and how many each has used
+    pub max_rebuys: u32,
+    pub player1_rebuys: u32,
+    pub player2_rebuys: u32,
+}
+
+/// `Game` storage layout 1, kept so `migrate` can decode games written by
+/// the original heads-up contract, before blinds, betting structures,
+/// multi-hand matches and every later field were added
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameV1 {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub player1_stack: i128,
+    pub player2_stack: i128,
+    pub player1_bet: i128,
+    pub player2_bet: i128,
+    pub pot: i128,
+    pub player1_hole_commitment: Option<Bytes>,
+    pub player2_hole_commitment: Option<Bytes>,
+    pub community_cards: Vec<u32>,
+    pub community_commitment: Option<Bytes>,
+    pub community_revealed: u32,
+    pub current_actor: u32,
+    pub last_action: Action,
+    pub last_raise_amount: i128,
+    pub actions_this_round: u32,
+    pub player1_revealed: bool,
+    pub player2_revealed: bool,
+    pub player1_ranking: Option<u32>,
+    pub player2_ranking: Option<u32>,
+    pub winner: Option<Address>,
+    pub phase: Phase,
+}
+
+/// A card decoded from the 0-51 encoding shared with the circuits:
+/// `card = suit * 13 + rank`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Card {
+    pub rank: u32,  // 0 = Two ... 12 = Ace
+    pub suit: u32,  // 0-3
+}
+
+/// Hand ranking scale output by the showdown circuit (higher is stronger)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HandRank {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPair = 2,
+    ThreeOfAKind = 3,
+    Straight = 4,
+    Flush = 5,
+    FullHouse = 6,
+    FourOfAKind = 7,
+    StraightFlush = 8,
+    RoyalFlush = 9,
+}
+
+/// Result imposed by an admin `force_end`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ForceOutcome {
+    Player1Wins,
+    Player2Wins,
+    Draw,
+}
+
+/// A scheduled `force_end` waiting out its delay
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingForceEnd {
+    pub outcome: ForceOutcome,
+    pub executable_at: u32,  // Ledger sequence from which it may run
+}
+
+/// Lifetime counters for a player across all sessions
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub hands_played: u32,
+    pub hands_won: u32,
+    pub total_pot_won: i128,
+    pub folds: u32,
+}
+
+/// Compact game state for polling UIs (no commitments or board)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSummary {
+    pub phase: Phase,
+    pub pot: i128,
+    pub player1_stack: i128,
+    pub player2_stack: i128,
+    pub current_actor: u32,
+    pub winner: Option<Address>,
+}
+
+/// Table listing data for lobby UIs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableMetadata {
+    pub name: Option<Symbol>,
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub ante: i128,
+    pub blind_level: u32,
+    pub structure: BettingStructure,
+    pub max_hands: u32,
+    pub hand_number: u32,
+}
+
+/// One betting action in a session's hand history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    pub hand_number: u32,
+    pub actor: Address,
+    pub action: Action,
+    pub amount: i128,  // Chips moved from the actor's stack into the pot
+    pub phase: Phase,
+    pub ledger: u32,
 }
 
 #[contracttype]
@@ -149,7 +436,25 @@ pub enum DataKey {
     Game(u32),
     GameHubAddress,
     Admin,
-    VerificationKey,  // Store verification key for ZK proofs
+    VerificationKey(u32),  // Verification keys for ZK proofs, by version
+    CurrentVkVersion,      // Version assigned to newly started games
+    PlayerSessions(Address),  // Recent session IDs per player (most recent last)
+    NextProofNonce,        // Counter for per-hand showdown proof nonces
+    HistoryLen(u32),       // Number of history entries for a session
+    Paused,                // Set by admin to halt new games and betting
+    SchemaVersion,         // Game storage layout version of the deployed WASM
+    GameSchema(u32),       // Game storage layout version a session was written with
+    MinBuyIn,              // Smallest accepted buy-in per player
+    MaxBuyIn,              // Largest accepted buy-in per player
+    RakeBps,               // Rake taken from each completed hand's pot (basis points)
+    Treasury,              // Address entitled to the collected rake
+    AccumulatedRake(Address),  // Rake collected per escrow token since the last withdrawal
+    HistoryEntry(u32, u32),  // (session_id, index) -> HistoryEntry
+    ProofNullifiers(u32),  // Hashes of every proof accepted in a session
+    HubPending(u32),       // Completed session whose GameHub end_game call failed
+    ForceEnd(u32),         // Admin force_end scheduled for a session
+    PlayerStats(Address),  // Lifetime PlayerStats (persistent)
+    DevMode,               // Skip Groth16 verification (test networks only)
 }
 
 // ============================================================================
@@ -162,6 +467,73 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers allowed for submitting the showdown proof (~1 day at 5s per ledger)
+const SHOWDOWN_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Ledgers allowed for both hole commitments to arrive (~1 day at 5s per ledger)
+const COMMIT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Delay between proposing and executing an admin force_end (~3 days at 5s per ledger)
+const FORCE_END_DELAY_LEDGERS: u32 = 51_840;
+
+/// Consecutive sat-out hands after which the session is awarded to the opponent
+const MAX_SIT_OUT_HANDS: u32 = 3;
+
+/// Current `Game` storage layout version
+/// Bump when the `Game` struct changes, keep the old struct and add a conversion arm to `migrate`
+const GAME_SCHEMA_VERSION: u32 = 2;
+
+/// Maximum session IDs kept in a player's session index (oldest dropped first)
+const MAX_PLAYER_SESSIONS: u32 = 50;
+
+/// Maximum history entries returned per `get_history` page
+const MAX_HISTORY_PAGE: u32 = 50;
+
+// ============================================================================
+// Rake
+// ============================================================================
+
+/// Basis points denominator (10_000 = 100%)
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Maximum rake the admin can configure (10%)
+const MAX_RAKE_BPS: u32 = 1_000;
+
+// ============================================================================
+// Betting Structures
+// ============================================================================
+
+/// Default maximum bets + raises per betting round in fixed-limit games
+const FIXED_LIMIT_RAISE_CAP: u32 = 4;
+
+// ============================================================================
+// Showdown
+// ============================================================================
+
+/// Hand result passed in place of a winner index when the pot is split evenly
+const CHOPPED: u32 = 2;
+
+// ============================================================================
+// Cards
+// ============================================================================
+
+/// Cards in the deck (encoded 0-51)
+const DECK_SIZE: u32 = 52;
+
+/// Ranks per suit in the card encoding
+const RANKS_PER_SUIT: u32 = 13;
+
+// ============================================================================
+// Dev Mode
+// ============================================================================
+
+/// Networks where dev mode may skip proof verification
+const TEST_NETWORK_PASSPHRASES: [&[u8]; 3] = [
+    b"Test SDF Network ; September 2015",
+    b"Test SDF Future Network ; October 2022",
+    b"Standalone Network ; February 2017",
+];
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -182,6 +554,9 @@ impl PockerContract {
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &game_hub);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &GAME_SCHEMA_VERSION);
     }
 
     /// Start a new game between two players with points.
@@ -193,6 +568,7 @@ impl PockerContract {
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1 (buy-in)
     /// * `player2_points` - Points amount committed by player 2 (buy-in)
+    /// * `config` - Table configuration (blinds)
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -200,68 +576,185 @@ impl PockerContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        config: TableConfig,
     ) -> Result<(), Error> {
+        if Self::is_paused(env.clone()) {
+            return Err(Error::ContractPaused);
+        }
+
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic_with_error!(&env, Error::NotPlayer);
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        // Blinds must be positive with the big blind at least the small blind
+        if config.small_blind <= 0 || config.big_blind < config.small_blind {
+            return Err(Error::InvalidBlinds);
+        }
+        if config.ante < 0 {
+            return Err(Error::InvalidBlinds);
+        }
+        if config.max_hands == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        // Bounties are only collectable by busting someone over several hands
+        if config.bounty_bps >= BPS_DENOMINATOR as u32
+            || (config.bounty_bps > 0 && config.max_hands == 1)
+        {
+            return Err(Error::InvalidConfig);
+        }
+        // Rebuys happen between hands and need escrowed tokens to top up with
+        if config.max_rebuys > 0 && (config.max_hands == 1 || config.escrow_token.is_none()) {
+            return Err(Error::InvalidConfig);
+        }
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Stakes must be strictly positive, and equal in matched-stakes mode
+        if player1_points <= 0 || player2_points <= 0 {
+            return Err(Error::InvalidStake);
+        }
+        if config.matched_stakes && player1_points != player2_points {
+            return Err(Error::InvalidStake);
+        }
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        // Buy-ins must fall within the admin-configured bounds
+        let (min_buy_in, max_buy_in) = Self::get_buy_in_bounds(env.clone());
+        for buy_in in [player1_points, player2_points] {
+            if buy_in < min_buy_in || buy_in > max_buy_in {
+                return Err(Error::InvalidBuyIn);
+            }
+        }
 
-        // Call Game Hub to start the session and lock points
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+        if let Some(tournament) = &config.tournament {
+            if tournament.hands_per_level == 0 || tournament.levels.is_empty() {
+                return Err(Error::InvalidConfig);
+            }
+            for level in tournament.levels.iter() {
+                if level.small_blind <= 0 || level.big_blind < level.small_blind || level.ante < 0 {
+                    return Err(Error::InvalidBlinds);
+                }
+            }
+        }
+
+        // Require authentication from both players (they consent to committing points and the table config)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env), config.clone().into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env), config.clone().into_val(&env)]);
+
+        // Knockout bounties come out of the buy-in before it becomes a stack
+        let player1_bounty = player1_points * config.bounty_bps as i128 / BPS_DENOMINATOR;
+        let player2_bounty = player2_points * config.bounty_bps as i128 / BPS_DENOMINATOR;
+
+        if let Some(escrow_token) = &config.escrow_token {
+            // Escrow mode: take the buy-ins into this contract
+            let token = token::TokenClient::new(&env, escrow_token);
+            let contract = env.current_contract_address();
+            token.transfer(&player1, &contract, &player1_points);
+            token.transfer(&player2, &contract, &player2_points);
+        } else {
+            // Get GameHub address
+            let game_hub_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::GameHubAddress)
+                .expect("GameHub address not set");
+
+            // Create GameHub client
+            let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+            // Call Game Hub to start the session and lock points
+            game_hub.start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &player1,
+                &player2,
+                &player1_points,
+                &player2_points,
+            );
+        }
 
         // Create game in Commit phase
         // Players start with their full buy-in as stack
-        // For 5-card poker (no community cards), set a dummy community commitment
-        let dummy_community_commitment = Bytes::from_slice(&env, &[0u8; 32]);
-        
-        let game = Game {
+        let mut game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_stack: player1_points,
-            player2_stack: player2_points,
+            player1_stack: player1_points - player1_bounty,
+            player2_stack: player2_points - player2_bounty,
             player1_bet: 0,
             player2_bet: 0,
             pot: 0,
+            side_pot: 0,
+            side_pot_owner: 0,
+            small_blind: config.small_blind,
+            big_blind: config.big_blind,
+            ante: config.ante,
+            // Button alternates between consecutive sessions
+            dealer: session_id % 2,
+            hand_number: 1,
+            max_hands: config.max_hands,
+            tournament: config.tournament.clone(),
+            blind_level: 0,
             player1_hole_commitment: None,
             player2_hole_commitment: None,
-            community_cards: Vec::new(&env),  // Will be generated when both players commit
-            community_commitment: Some(dummy_community_commitment),  // Dummy for 5-card poker
+            community_cards: Vec::new(&env),  // Dealt street by street after both players commit
+            community_commitment: None,  // Derived once both players commit
             community_revealed: 0,
-            current_actor: 0,  // Player 1 starts
+            current_actor: 0,  // Set to the dealer when blinds are posted
             last_action: Action::None,
             last_raise_amount: 0,
             actions_this_round: 0,
+            raises_this_round: 0,
+            last_aggressor: None,
+            betting_reopened: true,
+            raise_cap: if config.raise_cap == 0 && config.structure == BettingStructure::FixedLimit {
+                FIXED_LIMIT_RAISE_CAP
+            } else {
+                config.raise_cap
+            },
+            structure: config.structure,
             player1_revealed: false,
             player2_revealed: false,
             player1_ranking: None,
             player2_ranking: None,
+            player1_second_ranking: None,
+            player2_second_ranking: None,
             winner: None,
             phase: Phase::Commit,
+            vk_version: env
+                .storage()
+                .instance()
+                .get(&DataKey::CurrentVkVersion)
+                .unwrap_or(0),
+            proof_nonce: Self::next_proof_nonce(&env),
+            showdown_deadline: None,
+            commit_deadline: env.ledger().sequence() + COMMIT_TIMEOUT_LEDGERS,
+            player1_run_twice: false,
+            player2_run_twice: false,
+            second_board: Vec::new(&env),
+            second_board_commitment: None,
+            first_board_winner: None,
+            second_board_winner: None,
+            agreed_split_bps: None,
+            player1_sitting_out: false,
+            player2_sitting_out: false,
+            player1_missed_hands: 0,
+            player2_missed_hands: 0,
+            table_name: config.name.clone(),
+            escrow_token: config.escrow_token.clone(),
+            player1_bounty,
+            player2_bounty,
+            bounty_winner: None,
+            parent: config.parent.clone(),
+            max_rebuys: config.max_rebuys,
+            player1_rebuys: 0,
+            player2_rebuys: 0,
         };
 
+        // Post antes and blinds into the pot so preflop betting starts from SB/BB
+        Self::apply_blind_level(&mut game);
+        Self::post_antes(&mut game);
+        Self::post_blinds(&mut game);
+
         // Store game in temporary storage with 30-day TTL
         let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
@@ -271,9 +764,41 @@ impl PockerContract {
             .temporary()
             .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        // Record the layout this game was written with
+        let schema_key = DataKey::GameSchema(session_id);
+        env.storage().temporary().set(&schema_key, &GAME_SCHEMA_VERSION);
+        env.storage()
+            .temporary()
+            .extend_ttl(&schema_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Index the session for both players' wallets
+        Self::index_player_session(&env, &player1, session_id);
+        Self::index_player_session(&env, &player2, session_id);
+
         Ok(())
     }
 
+    /// Append a session to a player's session index
+    /// The index lives as long as the games it points to (30-day TTL)
+    fn index_player_session(env: &Env, player: &Address, session_id: u32) {
+        let key = DataKey::PlayerSessions(player.clone());
+        let mut sessions: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        if sessions.len() >= MAX_PLAYER_SESSIONS {
+            sessions.pop_front();
+        }
+        sessions.push_back(session_id);
+
+        env.storage().temporary().set(&key, &sessions);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
     /// Submit a commitment for your 2 hole cards (Poseidon hash)
     /// Players must commit before betting begins
     ///
@@ -302,6 +827,16 @@ impl PockerContract {
             return Err(Error::NotInPhase);
         }
 
+        // A busted player must rebuy before playing the hand
+        let (stack, bet) = if player == game.player1 {
+            (game.player1_stack, game.player1_bet)
+        } else {
+            (game.player2_stack, game.player2_bet)
+        };
+        if stack + bet == 0 {
+            return Err(Error::InsufficientStack);
+        }
+
         // Store commitment for the appropriate player
         if player == game.player1 {
             if game.player1_hole_commitment.is_some() {
@@ -317,9 +852,13 @@ impl PockerContract {
             return Err(Error::NotPlayer);
         }
 
-        // If both players have committed, move directly to Showdown (5-card poker, no community cards)
-        if game.player1_hole_commitment.is_some() && game.player2_hole_commitment.is_some() {
-            game.phase = Phase::Showdown;
+        // If both players have committed, fix the board and begin preflop betting
+        if let (Some(p1_commitment), Some(p2_commitment)) =
+            (&game.player1_hole_commitment, &game.player2_hole_commitment)
+        {
+            let cards = Self::generate_community_cards_secure(&env, session_id, p1_commitment, p2_commitment);
+            game.community_commitment = Some(Self::hash_community_cards(&env, &cards));
+            game.phase = Phase::Preflop;
         }
 
         // Store updated game in temporary storage
@@ -331,18 +870,21 @@ impl PockerContract {
         Ok(())
     }
 
-    /// Submit community cards commitment (5 cards)
-    /// This should be done after hole cards are committed
+    /// Submit both players' hole commitments in one (relayed) transaction
+    ///
+    /// Each player signs only their own commitment, so neither can stall
+    /// after seeing whether and when the other committed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `community_commitment` - Poseidon hash of 5 community cards + salt
-    pub fn submit_community_commitment(
+    /// * `player1_commitment` - Player1's Poseidon hash of 2 hole cards + salt
+    /// * `player2_commitment` - Player2's Poseidon hash of 2 hole cards + salt
+    pub fn submit_both_commitments(
         env: Env,
         session_id: u32,
-        community_commitment: Bytes,
+        player1_commitment: Bytes,
+        player2_commitment: Bytes,
     ) -> Result<(), Error> {
-        // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -350,15 +892,26 @@ impl PockerContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is in Preflop phase or later
-        if game.phase == Phase::Commit {
+        game.player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_commitment.clone().into_val(&env)]);
+        game.player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_commitment.clone().into_val(&env)]);
+
+        if game.phase != Phase::Commit {
             return Err(Error::NotInPhase);
         }
+        if game.player1_hole_commitment.is_some() || game.player2_hole_commitment.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+        // Busted players must rebuy before playing the hand
+        if game.player1_stack + game.player1_bet == 0 || game.player2_stack + game.player2_bet == 0 {
+            return Err(Error::InsufficientStack);
+        }
 
-        // Store community commitment
-        game.community_commitment = Some(community_commitment);
+        let cards = Self::generate_community_cards_secure(&env, session_id, &player1_commitment, &player2_commitment);
+        game.community_commitment = Some(Self::hash_community_cards(&env, &cards));
+        game.player1_hole_commitment = Some(player1_commitment);
+        game.player2_hole_commitment = Some(player2_commitment);
+        game.phase = Phase::Preflop;
 
-        // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
@@ -381,6 +934,10 @@ impl PockerContract {
     ) -> Result<(), Error> {
         player.require_auth();
 
+        if Self::is_paused(env.clone()) {
+            return Err(Error::ContractPaused);
+        }
+
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -395,6 +952,11 @@ impl PockerContract {
             return Err(Error::NotInPhase);
         }
 
+        // Community cards for this street must be dealt before betting
+        if game.community_revealed < Self::community_cards_for(&game.phase) {
+            return Err(Error::NotInPhase);
+        }
+
         // Check it's the player's turn
         let is_player1 = player == game.player1;
         let is_player2 = player == game.player2;
@@ -405,7 +967,7 @@ impl PockerContract {
 
         let player_index: u32 = if is_player1 { 0 } else { 1 };
         if player_index != game.current_actor {
-            return Err(Error::NotInPhase);  // Not your turn
+            return Err(Error::NotYourTurn);
         }
 
         // Get current player's stack and bet
@@ -415,18 +977,19 @@ impl PockerContract {
             (game.player2_stack, game.player2_bet, game.player1_bet)
         };
 
+        // Snapshot for the hand history before state changes
+        let recorded_action = action.clone();
+        let action_phase = game.phase.clone();
+
         // Process action
         match action {
             Action::Fold => {
-                // Player folds - opponent wins immediately
-                let winner = if is_player1 {
-                    game.player2.clone()
-                } else {
-                    game.player1.clone()
-                };
-                
-                game.winner = Some(winner.clone());
-                game.phase = Phase::Complete;
+                Self::record_history(&env, session_id, &game, &player, recorded_action, 0, action_phase);
+                Self::update_stats(&env, &player, |stats| stats.folds += 1);
+
+                // Player folds - opponent wins the hand immediately
+                let winner_index = if is_player1 { 1 } else { 0 };
+                let session_over = Self::finish_hand(&env, &mut game, winner_index);
                 
                 // Store updated game
                 env.storage().temporary().set(&key, &game);
@@ -434,22 +997,17 @@ impl PockerContract {
                     .temporary()
                     .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-                // Call GameHub to end the session
-                let game_hub_addr: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::GameHubAddress)
-                    .expect("GameHub address not set");
-                let game_hub = GameHubClient::new(&env, &game_hub_addr);
-                let player1_won = winner == game.player1;
-                game_hub.end_game(&session_id, &player1_won);
+                // Call GameHub (or release the escrow) to end the session
+                if session_over {
+                    Self::end_session(&env, session_id, &game);
+                }
 
                 return Ok(());
             }
             Action::Check => {
                 // Can only check if no bet to call
                 if opponent_bet > player_bet {
-                    return Err(Error::NotInPhase);
+                    return Err(Error::CannotCheck);
                 }
                 game.last_action = Action::Check;
             }
@@ -457,7 +1015,7 @@ impl PockerContract {
                 // Match opponent's bet
                 let call_amount = opponent_bet - player_bet;
                 if call_amount > player_stack {
-                    return Err(Error::NotInPhase);  // Not enough chips
+                    return Err(Error::InsufficientStack);
                 }
                 
                 if is_player1 {
@@ -474,10 +1032,21 @@ impl PockerContract {
             Action::Bet(amount) => {
                 // Initial bet in the round
                 if opponent_bet > 0 || player_bet > 0 {
-                    return Err(Error::NotInPhase);  // Already betting
+                    return Err(Error::InvalidBetSize);  // Already betting, must raise
+                }
+                if amount <= 0 {
+                    return Err(Error::InvalidBetSize);
                 }
                 if amount > player_stack {
-                    return Err(Error::NotInPhase);  // Not enough chips
+                    return Err(Error::InsufficientStack);
+                }
+                if let Some(max_total) = Self::max_bet_total(&game, opponent_bet, player_bet) {
+                    if amount > max_total {
+                        return Err(Error::InvalidBetSize);  // Over the pot/limit
+                    }
+                }
+                if game.structure == BettingStructure::FixedLimit && amount != Self::limit_bet_size(&game) {
+                    return Err(Error::InvalidBetSize);  // Limit bets are fixed
                 }
                 
                 if is_player1 {
@@ -490,16 +1059,37 @@ impl PockerContract {
                 
                 game.pot += amount;
                 game.last_raise_amount = amount;
+                game.raises_this_round += 1;
+                game.last_aggressor = Some(player_index);
                 game.last_action = Action::Bet(amount);
             }
             Action::Raise(amount) => {
-                // CRITICAL FIX #4: Proper no-limit poker raise logic
-                // Raise must be at least: opponent_bet + last_raise_amount
-                let call_amount = opponent_bet - player_bet;
-                let min_raise_total = opponent_bet + game.last_raise_amount.max(opponent_bet);
+                // A short all-in does not reopen betting to a player who already acted
+                if !game.betting_reopened {
+                    return Err(Error::InvalidBetSize);
+                }
+                if Self::raise_cap_reached(&game) {
+                    return Err(Error::RaiseCapReached);
+                }
+
+                // Raise must be at least: opponent_bet + last full raise
+                let min_raise_total = opponent_bet + Self::min_raise_size(&game);
                 
-                if amount < min_raise_total || amount > player_stack + player_bet {
-                    return Err(Error::NotInPhase);
+                if amount > player_stack + player_bet {
+                    return Err(Error::InsufficientStack);
+                }
+                if amount < min_raise_total {
+                    return Err(Error::InvalidBetSize);  // Undersized raise
+                }
+                if let Some(max_total) = Self::max_bet_total(&game, opponent_bet, player_bet) {
+                    if amount > max_total {
+                        return Err(Error::InvalidBetSize);  // Over the pot/limit
+                    }
+                }
+                if game.structure == BettingStructure::FixedLimit {
+                    if amount != opponent_bet + Self::limit_bet_size(&game) {
+                        return Err(Error::InvalidBetSize);  // Limit raises are fixed
+                    }
                 }
                 
                 let raise_amount = amount - player_bet;
@@ -513,9 +1103,39 @@ impl PockerContract {
                 
                 game.pot += raise_amount;
                 game.last_raise_amount = amount - opponent_bet;  // Track actual raise size
+                game.raises_this_round += 1;
+                game.last_aggressor = Some(player_index);
                 game.last_action = Action::Raise(amount);
             }
             Action::AllIn => {
+                // Shoving is only allowed within the pot/limit cap
+                let all_in_total = player_stack + player_bet;
+                if let Some(max_total) = Self::max_bet_total(&game, opponent_bet, player_bet) {
+                    if all_in_total > max_total {
+                        return Err(Error::InvalidBetSize);
+                    }
+                }
+                if all_in_total > opponent_bet {
+                    if !game.betting_reopened {
+                        return Err(Error::InvalidBetSize);  // Call or fold only
+                    }
+                    if Self::raise_cap_reached(&game) {
+                        return Err(Error::RaiseCapReached);
+                    }
+
+                    // Only a full bet or raise resets the minimum raise; a short
+                    // all-in leaves it alone and locks out a player who already acted
+                    let raise_size = all_in_total - opponent_bet;
+                    if opponent_bet == 0 || raise_size >= Self::min_raise_size(&game) {
+                        game.last_raise_amount = raise_size;
+                    } else if game.actions_this_round > 0 {
+                        game.betting_reopened = false;
+                    }
+
+                    game.raises_this_round += 1;
+                    game.last_aggressor = Some(player_index);
+                }
+
                 // Bet entire stack
                 if is_player1 {
                     game.pot += game.player1_stack;
@@ -533,11 +1153,20 @@ impl PockerContract {
             }
         }
 
+        let stack_after = if is_player1 { game.player1_stack } else { game.player2_stack };
+        Self::record_history(&env, session_id, &game, &player, recorded_action, player_stack - stack_after, action_phase);
+
         // Increment action counter
         game.actions_this_round += 1;
 
+        // Move any bet the all-in opponent cannot match into a side pot
+        Self::split_side_pot(&mut game);
+
         // CRITICAL FIX: Check if betting round is complete BEFORE switching turns
         if Self::is_betting_round_complete(&game) {
+            // Return the uncalled all-in excess to its owner
+            Self::refund_uncalled(&mut game);
+
             // Move to next phase
             game.phase = match game.phase {
                 Phase::Preflop => Phase::Flop,
@@ -546,13 +1175,17 @@ impl PockerContract {
                 Phase::River => Phase::Showdown,
                 _ => game.phase,
             };
+            Self::start_showdown_clock(&env, &mut game);
             
             // Reset bets for next round
             game.player1_bet = 0;
             game.player2_bet = 0;
-            game.current_actor = 0;  // Player 1 acts first post-flop
+            game.current_actor = Self::out_of_position(&game);  // Big blind acts first post-flop
             game.last_action = Action::None;  // Reset last action for new round
             game.actions_this_round = 0;  // Reset action counter for new round
+            game.raises_this_round = 0;
+            game.last_raise_amount = 0;
+            game.betting_reopened = true;
         } else {
             // Round not complete - switch to next player
             game.current_actor = if game.current_actor == 0 { 1 } else { 0 };
@@ -567,42 +1200,479 @@ impl PockerContract {
         Ok(())
     }
 
-    /// Check if betting round is complete
-    /// CRITICAL FIX #7: Use action counter to ensure both players have acted
-    /// 
-    /// A betting round is complete when:
-    /// 1. Both players have acted (actions_this_round >= 2)
-    /// 2. Bets are equal
-    /// 3. Last action is a "closing" action (Call/Check/AllIn)
-    /// 
-    /// This prevents the bug where Player 1 checks and immediately advances
-    /// to the next phase without Player 2 getting a turn.
-    fn is_betting_round_complete(game: &Game) -> bool {
-        // Fold always ends the round immediately
-        if matches!(game.last_action, Action::Fold) {
-            return true;
-        }
-        
-        // Both players must have acted at least once
-        if game.actions_this_round < 2 {
-            return false;
+    /// Largest total bet the acting player may make this street
+    ///
+    /// * NoLimit - no cap (the stack is the only limit)
+    /// * PotLimit - call, then raise by the size of the pot after the call
+    /// * FixedLimit - opponent's bet plus one fixed bet size
+    fn max_bet_total(game: &Game, opponent_bet: i128, player_bet: i128) -> Option<i128> {
+        match game.structure {
+            BettingStructure::NoLimit => None,
+            BettingStructure::PotLimit => {
+                let call_amount = opponent_bet - player_bet;
+                Some(opponent_bet + game.pot + call_amount)
+            }
+            BettingStructure::FixedLimit => Some(opponent_bet + Self::limit_bet_size(game)),
         }
-        
-        // Bets must be equal for round to be complete
-        if game.player1_bet != game.player2_bet {
-            return false;
+    }
+
+    /// Whether the round has used up its bets + raises
+    fn raise_cap_reached(game: &Game) -> bool {
+        game.raise_cap > 0 && game.raises_this_round >= game.raise_cap
+    }
+
+    /// Smallest legal raise increment: the fixed bet size in limit games,
+    /// otherwise the last full bet or raise this street (at least the big blind)
+    fn min_raise_size(game: &Game) -> i128 {
+        if game.structure == BettingStructure::FixedLimit {
+            Self::limit_bet_size(game)
+        } else {
+            game.last_raise_amount.max(game.big_blind)
         }
-        
-        // Check for all-in scenario
-        let p1_all_in = game.player1_stack == 0;
-        let p2_all_in = game.player2_stack == 0;
-        if p1_all_in || p2_all_in {
-            return true; // All-in with equal bets ends round
+    }
+
+    /// Fixed-limit bet size: small bet (big blind) preflop and on the flop,
+    /// big bet (two big blinds) on the turn and river
+    fn limit_bet_size(game: &Game) -> i128 {
+        match game.phase {
+            Phase::Turn | Phase::River => game.big_blind * 2,
+            _ => game.big_blind,
         }
-        
-        // Round is complete if last action was a "closing" action:
-        // - Call: Player matched opponent's bet
-        // - Check: Player checked with no bet to call
+    }
+
+    /// Seat index of the player out of position (the big blind)
+    fn out_of_position(game: &Game) -> u32 {
+        1 - game.dealer
+    }
+
+    /// Allocate a unique nonce for binding a hand's showdown proof
+    fn next_proof_nonce(env: &Env) -> u32 {
+        let nonce: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextProofNonce)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextProofNonce, &(nonce + 1));
+        nonce
+    }
+
+    /// Set blinds and ante from the tournament schedule for the current hand
+    fn apply_blind_level(game: &mut Game) {
+        if let Some(tournament) = &game.tournament {
+            let level = ((game.hand_number - 1) / tournament.hands_per_level)
+                .min(tournament.levels.len() - 1);
+            let blinds = tournament.levels.get(level).unwrap();
+
+            game.small_blind = blinds.small_blind;
+            game.big_blind = blinds.big_blind;
+            game.ante = blinds.ante;
+            game.blind_level = level;
+        }
+    }
+
+    /// Post the ante from both stacks into the pot
+    /// Antes are dead money: they go to the pot but do not count as a bet
+    fn post_antes(game: &mut Game) {
+        let p1_ante = game.ante.min(game.player1_stack);
+        let p2_ante = game.ante.min(game.player2_stack);
+
+        game.player1_stack -= p1_ante;
+        game.player2_stack -= p2_ante;
+        game.pot += p1_ante + p2_ante;
+    }
+
+    /// Post small and big blinds from the stacks into the pot
+    ///
+    /// Heads-up rules: the dealer posts the small blind and acts first preflop.
+    /// A player whose stack is smaller than the blind posts what they have.
+    fn post_blinds(game: &mut Game) {
+        let dealer_is_p1 = game.dealer == 0;
+
+        let (sb_stack, bb_stack) = if dealer_is_p1 {
+            (game.player1_stack, game.player2_stack)
+        } else {
+            (game.player2_stack, game.player1_stack)
+        };
+        let sb = game.small_blind.min(sb_stack);
+        let bb = game.big_blind.min(bb_stack);
+
+        if dealer_is_p1 {
+            game.player1_stack -= sb;
+            game.player1_bet = sb;
+            game.player2_stack -= bb;
+            game.player2_bet = bb;
+        } else {
+            game.player2_stack -= sb;
+            game.player2_bet = sb;
+            game.player1_stack -= bb;
+            game.player1_bet = bb;
+        }
+
+        game.pot += sb + bb;
+        game.last_raise_amount = game.big_blind;
+        game.current_actor = game.dealer;
+    }
+
+    /// Append a betting action to the session's history log
+    fn record_history(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        actor: &Address,
+        action: Action,
+        amount: i128,
+        phase: Phase,
+    ) {
+        let len_key = DataKey::HistoryLen(session_id);
+        let index: u32 = env.storage().temporary().get(&len_key).unwrap_or(0);
+
+        let entry = HistoryEntry {
+            hand_number: game.hand_number,
+            actor: actor.clone(),
+            action,
+            amount,
+            phase,
+            ledger: env.ledger().sequence(),
+        };
+
+        let entry_key = DataKey::HistoryEntry(session_id, index);
+        env.storage().temporary().set(&entry_key, &entry);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        env.storage().temporary().set(&len_key, &(index + 1));
+        env.storage()
+            .temporary()
+            .extend_ttl(&len_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Move the uncalled part of a bet into the side pot
+    ///
+    /// When the player with the smaller bet is all-in they can never match
+    /// the opponent's bet, so the excess is taken out of the main pot and
+    /// kept aside for the player who bet it. Bets are then equal again,
+    /// which lets the betting round close.
+    fn split_side_pot(game: &mut Game) {
+        if game.player1_bet == game.player2_bet {
+            return;
+        }
+
+        let p1_is_short = game.player1_bet < game.player2_bet;
+        let short_stack = if p1_is_short { game.player1_stack } else { game.player2_stack };
+        if short_stack != 0 {
+            return;
+        }
+
+        if p1_is_short {
+            let excess = game.player2_bet - game.player1_bet;
+            game.player2_bet = game.player1_bet;
+            game.pot -= excess;
+            game.side_pot += excess;
+            game.side_pot_owner = 1;
+        } else {
+            let excess = game.player1_bet - game.player2_bet;
+            game.player1_bet = game.player2_bet;
+            game.pot -= excess;
+            game.side_pot += excess;
+            game.side_pot_owner = 0;
+        }
+    }
+
+    /// Refund the uncalled side pot to the player who bet it
+    fn refund_uncalled(game: &mut Game) {
+        if game.side_pot_owner == 0 {
+            game.player1_stack += game.side_pot;
+        } else {
+            game.player2_stack += game.side_pot;
+        }
+        game.side_pot = 0;
+    }
+
+    /// Pay out the main pot to the winner and the side pot to its owner
+    fn settle_pots(game: &mut Game, winner_index: u32) {
+        // Agreed all-in settlement: pot split as co-signed by both players
+        if let Some(split_bps) = game.agreed_split_bps {
+            let player1_share = game.pot * split_bps as i128 / BPS_DENOMINATOR;
+            game.player1_stack += player1_share;
+            game.player2_stack += game.pot - player1_share;
+            game.pot = 0;
+        }
+
+        // Run it twice: half the pot per board (odd chip to the first board)
+        if let (Some(first), Some(second)) = (game.first_board_winner, game.second_board_winner) {
+            let half = game.pot / 2;
+            Self::award(game, half, second);
+            Self::award(game, game.pot - half, first);
+        } else {
+            Self::award(game, game.pot, winner_index);
+        }
+
+        if game.side_pot_owner == 0 {
+            game.player1_stack += game.side_pot;
+        } else {
+            game.player2_stack += game.side_pot;
+        }
+
+        game.pot = 0;
+        game.side_pot = 0;
+    }
+
+    /// Credit chips to a hand winner, or split them evenly (odd chip to player1) when CHOPPED
+    fn award(game: &mut Game, amount: i128, winner_index: u32) {
+        match winner_index {
+            0 => game.player1_stack += amount,
+            1 => game.player2_stack += amount,
+            _ => {
+                game.player1_stack += amount - amount / 2;
+                game.player2_stack += amount / 2;
+            }
+        }
+    }
+
+    /// Settle a finished hand and either start the next one or end the session
+    ///
+    /// Single-hand sessions are won by the hand winner. Multi-hand sessions
+    /// continue with carried-over stacks until a player is busted or the hand
+    /// limit is reached, then the bigger stack wins. Equal stacks end the
+    /// session with no winner.
+    ///
+    /// # Returns
+    /// * `bool` - True if the session is over and GameHub must be notified
+    fn finish_hand(env: &Env, game: &mut Game, winner_index: u32) -> bool {
+        Self::collect_rake(env, game);
+        Self::record_hand_stats(env, game, winner_index);
+        Self::settle_pots(game, winner_index);
+
+        // A busted player who can still rebuy stays in the session
+        let busted = (game.player1_stack == 0 && game.player1_rebuys >= game.max_rebuys)
+            || (game.player2_stack == 0 && game.player2_rebuys >= game.max_rebuys);
+        if game.max_hands > 1 && !busted && game.hand_number < game.max_hands {
+            Self::start_next_hand(env, game);
+            return false;
+        }
+
+        game.winner = if game.max_hands == 1 {
+            match winner_index {
+                0 => Some(game.player1.clone()),
+                1 => Some(game.player2.clone()),
+                _ => None,
+            }
+        } else if game.player1_stack > game.player2_stack {
+            Some(game.player1.clone())
+        } else if game.player2_stack > game.player1_stack {
+            Some(game.player2.clone())
+        } else {
+            None
+        };
+        game.phase = Phase::Complete;
+        Self::settle_bounties(game);
+        true
+    }
+
+    /// Count a finished hand in both players' lifetime stats
+    /// Called after rake, so the winner is credited with the pot actually paid
+    fn record_hand_stats(env: &Env, game: &Game, winner_index: u32) {
+        if winner_index == CHOPPED {
+            Self::update_stats(env, &game.player1, |stats| stats.hands_played += 1);
+            Self::update_stats(env, &game.player2, |stats| stats.hands_played += 1);
+            return;
+        }
+        let (winner, loser) = if winner_index == 0 {
+            (&game.player1, &game.player2)
+        } else {
+            (&game.player2, &game.player1)
+        };
+        let pot = game.pot;
+        Self::update_stats(env, winner, |stats| {
+            stats.hands_played += 1;
+            stats.hands_won += 1;
+            stats.total_pot_won += pot;
+        });
+        Self::update_stats(env, loser, |stats| stats.hands_played += 1);
+    }
+
+    /// Apply a change to a player's lifetime stats
+    fn update_stats(env: &Env, player: &Address, update: impl FnOnce(&mut PlayerStats)) {
+        let key = DataKey::PlayerStats(player.clone());
+        let mut stats: PlayerStats = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_default();
+        update(&mut stats);
+        env.storage().persistent().set(&key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Pay out the knockout bounties when a session ends
+    ///
+    /// A player who busts the opponent collects the opponent's bounty along
+    /// with their own; otherwise each bounty goes back to its owner's stack.
+    fn settle_bounties(game: &mut Game) {
+        let bounties = game.player1_bounty + game.player2_bounty;
+        if game.player2_stack == 0 && game.player1_stack > 0 {
+            game.player1_stack += bounties;
+            game.bounty_winner = Some(0);
+        } else if game.player1_stack == 0 && game.player2_stack > 0 {
+            game.player2_stack += bounties;
+            game.bounty_winner = Some(1);
+        } else {
+            game.player1_stack += game.player1_bounty;
+            game.player2_stack += game.player2_bounty;
+        }
+        game.player1_bounty = 0;
+        game.player2_bounty = 0;
+    }
+
+    /// Fold the fresh hand of a player who is sitting out
+    ///
+    /// Folds at most one hand; the next one waits for `fold_sitting_out` so
+    /// the player can still resume in between. Only applies when exactly one
+    /// player sits out (if both do, the hand waits for a resume). After
+    /// `MAX_SIT_OUT_HANDS` consecutive missed hands the session ends and the
+    /// opponent wins it.
+    ///
+    /// # Returns
+    /// * `bool` - True if the session is over and GameHub must be notified
+    fn auto_fold_sitting_out(env: &Env, game: &mut Game) -> bool {
+        let sitter: u32 = match (game.player1_sitting_out, game.player2_sitting_out) {
+            (true, false) => 0,
+            (false, true) => 1,
+            _ => return false,
+        };
+
+        let sitter_address = if sitter == 0 { game.player1.clone() } else { game.player2.clone() };
+        Self::update_stats(env, &sitter_address, |stats| stats.folds += 1);
+
+        let missed = if sitter == 0 {
+            game.player1_missed_hands += 1;
+            game.player1_missed_hands
+        } else {
+            game.player2_missed_hands += 1;
+            game.player2_missed_hands
+        };
+
+        if missed < MAX_SIT_OUT_HANDS {
+            return Self::finish_hand(env, game, 1 - sitter);
+        }
+
+        Self::collect_rake(env, game);
+        Self::record_hand_stats(env, game, 1 - sitter);
+        Self::settle_pots(game, 1 - sitter);
+        game.winner = Some(if sitter == 0 { game.player2.clone() } else { game.player1.clone() });
+        game.phase = Phase::Complete;
+        Self::settle_bounties(game);
+        true
+    }
+
+    /// Deduct the configured rake from the main pot before payout
+    /// The side pot is an uncalled return and is never raked. Only escrow
+    /// sessions are raked: hub-points sessions hold no tokens to withdraw.
+    fn collect_rake(env: &Env, game: &mut Game) {
+        let Some(escrow_token) = game.escrow_token.clone() else {
+            return;
+        };
+        let rake_bps: u32 = env.storage().instance().get(&DataKey::RakeBps).unwrap_or(0);
+        let rake = game.pot * rake_bps as i128 / BPS_DENOMINATOR;
+        if rake == 0 {
+            return;
+        }
+
+        game.pot -= rake;
+        let rake_key = DataKey::AccumulatedRake(escrow_token);
+        let accumulated: i128 = env
+            .storage()
+            .instance()
+            .get(&rake_key)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&rake_key, &(accumulated + rake));
+    }
+
+    /// Reset per-hand state, move the button and post blinds for the next hand
+    /// Players must submit fresh hole commitments for every hand
+    fn start_next_hand(env: &Env, game: &mut Game) {
+        game.hand_number += 1;
+        game.dealer = 1 - game.dealer;
+        game.proof_nonce = Self::next_proof_nonce(env);
+
+        game.player1_bet = 0;
+        game.player2_bet = 0;
+        game.player1_hole_commitment = None;
+        game.player2_hole_commitment = None;
+        game.community_cards = Vec::new(env);
+        game.community_commitment = None;
+        game.community_revealed = 0;
+        game.last_action = Action::None;
+        game.last_raise_amount = 0;
+        game.actions_this_round = 0;
+        game.raises_this_round = 0;
+        game.last_aggressor = None;
+        game.betting_reopened = true;
+        game.showdown_deadline = None;
+        game.commit_deadline = env.ledger().sequence() + COMMIT_TIMEOUT_LEDGERS;
+        game.player1_run_twice = false;
+        game.player2_run_twice = false;
+        game.second_board = Vec::new(env);
+        game.second_board_commitment = None;
+        game.first_board_winner = None;
+        game.second_board_winner = None;
+        game.agreed_split_bps = None;
+        game.player1_revealed = false;
+        game.player2_revealed = false;
+        game.player1_ranking = None;
+        game.player2_ranking = None;
+        game.player1_second_ranking = None;
+        game.player2_second_ranking = None;
+        game.phase = Phase::Commit;
+
+        Self::apply_blind_level(game);
+        Self::post_antes(game);
+        Self::post_blinds(game);
+    }
+
+    /// Check if betting round is complete
+    /// CRITICAL FIX #7: Use action counter to ensure both players have acted
+    /// 
+    /// A betting round is complete when:
+    /// 1. Both players have acted (actions_this_round >= 2)
+    /// 2. Bets are equal
+    /// 3. Last action is a "closing" action (Call/Check/AllIn)
+    /// 
+    /// This prevents the bug where Player 1 checks and immediately advances
+    /// to the next phase without Player 2 getting a turn.
+    fn is_betting_round_complete(game: &Game) -> bool {
+        // Fold always ends the round immediately
+        if matches!(game.last_action, Action::Fold) {
+            return true;
+        }
+        
+        // Both players must have acted at least once
+        if game.actions_this_round < 2 {
+            return false;
+        }
+        
+        // Bets must be equal for round to be complete
+        if game.player1_bet != game.player2_bet {
+            return false;
+        }
+        
+        // Check for all-in scenario
+        let p1_all_in = game.player1_stack == 0;
+        let p2_all_in = game.player2_stack == 0;
+        if p1_all_in || p2_all_in {
+            return true; // All-in with equal bets ends round
+        }
+        
+        // Round is complete if last action was a "closing" action:
+        // - Call: Player matched opponent's bet
+        // - Check: Player checked with no bet to call
         // - AllIn: Player went all-in
         //
         // Round is NOT complete if last action was:
@@ -612,7 +1682,8 @@ impl PockerContract {
             Action::Call | Action::AllIn => true,
             Action::Check => {
                 // Check is only valid if there's no bet to call
-                game.player1_bet == 0 && game.player2_bet == 0
+                // (bets are equal here, e.g. big blind checking its option)
+                game.player1_bet == game.player2_bet
             },
             Action::Bet(_) | Action::Raise(_) => {
                 // After a bet/raise, opponent must respond
@@ -624,34 +1695,14 @@ impl PockerContract {
     }
 
     /// Generate 5 deterministic community cards using commit-reveal randomness
-    /// CRITICAL FIX #3: Use both player commitments as seed to prevent prediction
-    fn generate_community_cards(env: &Env, session_id: u32) -> Vec<u32> {
-        // SECURITY: This will be called AFTER both players commit hole cards
-        // The seed combines session_id with player commitments (set externally)
-        // For now using session_id - should be enhanced with commitment-based seed
-        
-        // Use keccak256 hash of session_id as seed for deterministic randomness
-        let mut seed_bytes = Bytes::new(env);
-        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        let seed_hash = env.crypto().keccak256(&seed_bytes);
-        
-        let mut prng = env.prng();
-        prng.seed(seed_hash.into());
-        
-        // Create a deck of 52 cards (0-51)
-        let mut deck: Vec<u32> = Vec::new(env);
-        for i in 0u32..52u32 {
-            deck.push_back(i);
-        }
-        
-        // Fisher-Yates shuffle using PRNG
-        for i in (1u32..52u32).rev() {
-            let j = prng.gen_range::<u64>(0..((i + 1) as u64)) as u32;
-            // Swap deck[i] and deck[j]
-            let temp = deck.get(i).unwrap();
-            deck.set(i, deck.get(j).unwrap());
-            deck.set(j, temp);
-        }
+    /// Called when dealing each street, once both commitments are available
+    fn generate_community_cards_secure(
+        env: &Env,
+        session_id: u32,
+        p1_commitment: &Bytes,
+        p2_commitment: &Bytes,
+    ) -> Vec<u32> {
+        let deck = Self::shuffle_deck(env, session_id, p1_commitment, p2_commitment);
         
         // Take first 5 cards as community cards
         let mut community: Vec<u32> = Vec::new(env);
@@ -661,11 +1712,9 @@ impl PockerContract {
         
         community
     }
-    
-    /// Enhanced version: Generate community cards using commit-reveal randomness
-    /// TODO: Call this after both commitments are available
-    #[allow(dead_code)]
-    fn generate_community_cards_secure(
+
+    /// Shuffle the full 52-card deck from both players' commitments
+    fn shuffle_deck(
         env: &Env,
         session_id: u32,
         p1_commitment: &Bytes,
@@ -684,44 +1733,73 @@ impl PockerContract {
         
         // Create a deck of 52 cards (0-51)
         let mut deck: Vec<u32> = Vec::new(env);
-        for i in 0u32..52u32 {
+        for i in 0u32..DECK_SIZE {
             deck.push_back(i);
         }
         
         // Fisher-Yates shuffle using PRNG
-        for i in (1u32..52u32).rev() {
+        for i in (1u32..DECK_SIZE).rev() {
             let j = prng.gen_range::<u64>(0..((i + 1) as u64)) as u32;
             let temp = deck.get(i).unwrap();
             deck.set(i, deck.get(j).unwrap());
             deck.set(j, temp);
         }
         
-        // Take first 5 cards as community cards
-        let mut community: Vec<u32> = Vec::new(env);
-        for i in 0u32..5u32 {
-            community.push_back(deck.get(i).unwrap());
-        }
-        
-        community
+        deck
     }
 
-    /// Reveal the winner using a ZK proof
-    /// Verifies that revealed hands (2 hole cards + 5 community cards) match commitments and determines winner
+    /// Deal the flop (first 3 community cards)
+    /// Callable by anyone once preflop betting is complete
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `proof` - Groth16 ZK proof
-    /// * `public_signals` - Public signals from the proof (commitments, rankings, winner)
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(
-        env: Env,
-        session_id: u32,
-        proof: Groth16Proof,
-        public_signals: Vec<Bytes>,
-    ) -> Result<Address, Error> {
-        // Get game from temporary storage
+    /// * `Vec<u32>` - The community cards revealed so far
+    pub fn deal_flop(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        Self::deal_street(&env, session_id, Phase::Flop)
+    }
+
+    /// Deal the turn (4th community card)
+    /// Callable by anyone once flop betting is complete
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - The community cards revealed so far
+    pub fn deal_turn(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        Self::deal_street(&env, session_id, Phase::Turn)
+    }
+
+    /// Deal the river (5th community card)
+    /// Callable by anyone once turn betting is complete
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - The community cards revealed so far
+    pub fn deal_river(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        Self::deal_street(&env, session_id, Phase::River)
+    }
+
+    /// Number of community cards that must be revealed before betting in a phase
+    fn community_cards_for(phase: &Phase) -> u32 {
+        match phase {
+            Phase::Flop => 3,
+            Phase::Turn => 4,
+            Phase::River | Phase::Showdown => 5,
+            _ => 0,
+        }
+    }
+
+    /// Reveal community cards for `street` from the commitment-seeded shuffle
+    ///
+    /// The game must already be in `street` (the previous betting round closed)
+    /// with its cards not yet dealt. If a player is all-in there is no more
+    /// betting, so the game advances straight to the next street.
+    fn deal_street(env: &Env, session_id: u32, street: Phase) -> Result<Vec<u32>, Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -729,102 +1807,912 @@ impl PockerContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
-        }
-
-        // Check game is in Showdown phase
-        if game.phase != Phase::Showdown {
+        let target = Self::community_cards_for(&street);
+        if game.phase != street || game.community_revealed >= target {
             return Err(Error::NotInPhase);
         }
 
-        // Check both players have committed hole cards
-        if game.player1_hole_commitment.is_none() || game.player2_hole_commitment.is_none() {
-            return Err(Error::NotCommitted);
-        }
+        let p1_commitment = game.player1_hole_commitment.clone().ok_or(Error::NotCommitted)?;
+        let p2_commitment = game.player2_hole_commitment.clone().ok_or(Error::NotCommitted)?;
+        let cards = Self::generate_community_cards_secure(env, session_id, &p1_commitment, &p2_commitment);
 
-        // Verify ZK proof using Protocol 25 primitives
-        // public_signals format:
-        // [0] = player1_hole_commitment
-        // [1] = player2_hole_commitment
-        // [2] = community_commitment
-        // [3] = player1_ranking
-        // [4] = player2_ranking
-        // [5] = winner (1 = player1, 2 = player2, 0 = tie)
-        
-        if public_signals.len() < 6 {
-            return Err(Error::InvalidProof);
+        for i in game.community_revealed..target {
+            game.community_cards.push_back(cards.get(i).unwrap());
         }
+        game.community_revealed = target;
 
-        // CRITICAL: Verify ALL commitments match what was submitted
-        let proof_p1_commitment = public_signals.get(0).unwrap();
-        let proof_p2_commitment = public_signals.get(1).unwrap();
-        let proof_community_commitment = public_signals.get(2).unwrap();
-
-        if proof_p1_commitment != *game.player1_hole_commitment.as_ref().unwrap() {
-            return Err(Error::InvalidCommitment);
-        }
-        if proof_p2_commitment != *game.player2_hole_commitment.as_ref().unwrap() {
-            return Err(Error::InvalidCommitment);
-        }
-        
-        // CRITICAL FIX #1: Verify community commitment to prevent proof replay with different community cards
-        if game.community_commitment.is_none() {
-            return Err(Error::InvalidCommitment);
-        }
-        if proof_community_commitment != *game.community_commitment.as_ref().unwrap() {
-            return Err(Error::InvalidCommitment);
+        // All-in: no further betting, run out the board
+        if game.player1_stack == 0 || game.player2_stack == 0 {
+            game.phase = match game.phase {
+                Phase::Flop => Phase::Turn,
+                Phase::Turn => Phase::River,
+                Phase::River => Phase::Showdown,
+                _ => game.phase,
+            };
+            Self::start_showdown_clock(env, &mut game);
         }
 
-        // Verify the ZK proof
-        Self::verify_groth16_proof(&env, proof, public_signals.clone())?;
-
-        // Extract rankings and winner from public signals
-        let p1_ranking = Self::bytes_to_u32(&public_signals.get(3).unwrap());
-        let p2_ranking = Self::bytes_to_u32(&public_signals.get(4).unwrap());
-        let winner_signal = Self::bytes_to_u32(&public_signals.get(5).unwrap());
-
-        game.player1_ranking = Some(p1_ranking);
-        game.player2_ranking = Some(p2_ranking);
-        game.player1_revealed = true;
-        game.player2_revealed = true;
-
-        // Determine winner based on proof output
-        let winner = match winner_signal {
-            1 => game.player1.clone(),
-            2 => game.player2.clone(),
-            _ => {
-                // Tie - use deterministic tiebreaker (player1 wins)
-                game.player1.clone()
-            }
-        };
-
-        // Update game with winner
-        game.winner = Some(winner.clone());
-        game.phase = Phase::Complete;
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call GameHub to end the session
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
+        Ok(game.community_cards)
+    }
+
+    /// Rebuy chips between hands of a multi-hand escrow session
+    ///
+    /// Tops the player's stack back up to at most their original buy-in by
+    /// transferring `amount` of the escrow token in. Any blind the player
+    /// couldn't cover when the hand started is posted from the rebuy. Only
+    /// escrow sessions support rebuys: GameHub has no call to lock more points
+    /// into a running session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player rebuying
+    /// * `amount` - Chips to add
+    pub fn rebuy(env: Env, session_id: u32, player: Address, amount: i128) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        let escrow_token = game.escrow_token.clone().ok_or(Error::InvalidConfig)?;
+        if game.phase != Phase::Commit {
+            return Err(Error::NotInPhase);
+        }
+
+        let is_player1 = player == game.player1;
+        if !is_player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        let (rebuys, committed, stack, bet, buy_in) = if is_player1 {
+            (
+                game.player1_rebuys,
+                game.player1_hole_commitment.is_some(),
+                game.player1_stack,
+                game.player1_bet,
+                game.player1_points,
+            )
+        } else {
+            (
+                game.player2_rebuys,
+                game.player2_hole_commitment.is_some(),
+                game.player2_stack,
+                game.player2_bet,
+                game.player2_points,
+            )
+        };
+        if committed {
+            return Err(Error::AlreadyCommitted);
+        }
+        if rebuys >= game.max_rebuys {
+            return Err(Error::InvalidConfig);
+        }
+        if amount <= 0 || stack + bet + amount > buy_in {
+            return Err(Error::InvalidStake);
+        }
+
+        token::TokenClient::new(&env, &escrow_token).transfer(
+            &player,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        // Post whatever part of the blind the short stack couldn't
+        let seat = if is_player1 { 0 } else { 1 };
+        let blind = if seat == game.dealer { game.small_blind } else { game.big_blind };
+        let owed = (blind - bet).clamp(0, amount);
+        if is_player1 {
+            game.player1_stack += amount - owed;
+            game.player1_bet += owed;
+            game.player1_rebuys += 1;
+        } else {
+            game.player2_stack += amount - owed;
+            game.player2_bet += owed;
+            game.player2_rebuys += 1;
+        }
+        game.pot += owed;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Sit out of a multi-hand session
+    ///
+    /// If no cards are in play yet the current hand is folded right away.
+    /// Later hands still post the player's blinds and are folded one at a
+    /// time by the opponent via `fold_sitting_out` until the player resumes.
+    /// Sitting out `MAX_SIT_OUT_HANDS` hands in a row forfeits the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player sitting out
+    pub fn sit_out(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == Phase::Complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.max_hands <= 1 {
+            return Err(Error::InvalidConfig);
+        }
+        if player == game.player1 {
+            game.player1_sitting_out = true;
+        } else if player == game.player2 {
+            game.player2_sitting_out = true;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        // Nothing dealt yet: fold this hand right away
+        let session_over = game.phase == Phase::Commit
+            && Self::auto_fold_sitting_out(&env, &mut game);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        if session_over {
+            Self::end_session(&env, session_id, &game);
+        }
+
+        Ok(())
+    }
+
+    /// Fold the current hand of an opponent who is sitting out
+    ///
+    /// Only while the hand is waiting for commitments; folds that single
+    /// hand. The `MAX_SIT_OUT_HANDS`-th consecutive fold ends the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player still at the table
+    pub fn fold_sitting_out(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == Phase::Complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.phase != Phase::Commit {
+            return Err(Error::NotInPhase);
+        }
+        let (own_sitting_out, opponent_sitting_out) = if player == game.player1 {
+            (game.player1_sitting_out, game.player2_sitting_out)
+        } else if player == game.player2 {
+            (game.player2_sitting_out, game.player1_sitting_out)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if own_sitting_out || !opponent_sitting_out {
+            return Err(Error::NotSittingOut);
+        }
+
+        let session_over = Self::auto_fold_sitting_out(&env, &mut game);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        if session_over {
+            Self::end_session(&env, session_id, &game);
+        }
+
+        Ok(())
+    }
+
+    /// Return to play after sitting out
+    ///
+    /// Takes effect on the current hand if it is still waiting for
+    /// commitments, and resets the consecutive missed-hand count.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player resuming
+    pub fn resume(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == Phase::Complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player == game.player1 {
+            game.player1_sitting_out = false;
+            game.player1_missed_hands = 0;
+        } else if player == game.player2 {
+            game.player2_sitting_out = false;
+            game.player2_missed_hands = 0;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Opt in to running the board twice
+    ///
+    /// Available once a player is all-in and before the river is dealt. When
+    /// both players opt in, a second board is derived from the same shuffle:
+    /// it shares the cards already revealed and takes the rest from the deck
+    /// after the first board, so the two runouts never share unseen cards.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player opting in
+    pub fn opt_in_run_it_twice(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        let in_hand = game.phase == Phase::Preflop || game.phase == Phase::Flop
+            || game.phase == Phase::Turn || game.phase == Phase::River;
+        let all_in = game.player1_stack == 0 || game.player2_stack == 0;
+        if !in_hand || !all_in || game.community_revealed >= 5 || game.second_board_commitment.is_some() {
+            return Err(Error::NotInPhase);
+        }
+
+        if player == game.player1 {
+            game.player1_run_twice = true;
+        } else if player == game.player2 {
+            game.player2_run_twice = true;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_run_twice && game.player2_run_twice {
+            let p1_commitment = game.player1_hole_commitment.clone().ok_or(Error::NotCommitted)?;
+            let p2_commitment = game.player2_hole_commitment.clone().ok_or(Error::NotCommitted)?;
+            let deck = Self::shuffle_deck(&env, session_id, &p1_commitment, &p2_commitment);
+
+            let mut board: Vec<u32> = Vec::new(&env);
+            for i in 0..game.community_revealed {
+                board.push_back(deck.get(i).unwrap());
+            }
+            for i in 5..(10 - game.community_revealed) {
+                board.push_back(deck.get(i).unwrap());
+            }
+
+            game.second_board_commitment = Some(Self::hash_community_cards(&env, &board));
+            game.second_board = board;
+        }
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Settle an all-in hand early with a split both players agreed on
+    ///
+    /// Once a player is all-in there is no more betting, so both players can
+    /// co-sign a split of the pot (e.g. equity chop or insurance) and end the
+    /// hand without a showdown proof. Rake still applies. The bigger share
+    /// wins the hand; an even split counts as a chop.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player1_share_bps` - Player1's share of the pot in basis points (0-10000)
+    pub fn settle_all_in(env: Env, session_id: u32, player1_share_bps: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Both players must sign the same split
+        let args = vec![&env, session_id.into_val(&env), player1_share_bps.into_val(&env)];
+        game.player1.require_auth_for_args(args.clone());
+        game.player2.require_auth_for_args(args);
+
+        let live = game.phase != Phase::Commit && game.phase != Phase::Complete;
+        let all_in = game.player1_stack == 0 || game.player2_stack == 0;
+        if !live || !all_in || game.player1_bet != game.player2_bet {
+            return Err(Error::NotInPhase);
+        }
+        if player1_share_bps as i128 > BPS_DENOMINATOR {
+            return Err(Error::InvalidSplit);
+        }
+
+        game.agreed_split_bps = Some(player1_share_bps);
+        let doubled_share = player1_share_bps as i128 * 2;
+        let winner_index = if doubled_share > BPS_DENOMINATOR {
+            0
+        } else if doubled_share < BPS_DENOMINATOR {
+            1
+        } else {
+            CHOPPED
+        };
+        let session_over = Self::finish_hand(&env, &mut game, winner_index);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        if session_over {
+            Self::end_session(&env, session_id, &game);
+        }
+
+        Ok(())
+    }
+
+    /// Start the showdown proof deadline when the game reaches Showdown
+    fn start_showdown_clock(env: &Env, game: &mut Game) {
+        if game.phase == Phase::Showdown {
+            game.showdown_deadline = Some(env.ledger().sequence() + SHOWDOWN_TIMEOUT_LEDGERS);
+        }
+    }
+
+    /// Resolve a showdown that wasn't fully proven before the deadline
+    ///
+    /// If only one player proved their hand(s), the other forfeits. If
+    /// neither did, the last aggressor of the hand must show first; if nobody
+    /// bet or raised, the out-of-position player must. The forfeiting player
+    /// loses the hand, so a losing player can't freeze the pot by withholding
+    /// a proof.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Either player (caller)
+    ///
+    /// # Returns
+    /// * `Address` - Address of the player awarded the hand
+    pub fn claim_showdown_timeout(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<Address, Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.phase != Phase::Showdown {
+            return Err(Error::NotInPhase);
+        }
+        let deadline = game.showdown_deadline.ok_or(Error::NotInPhase)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let running_twice = game.second_board_commitment.is_some();
+        let player1_shown = game.player1_ranking.is_some()
+            && (!running_twice || game.player1_second_ranking.is_some());
+        let player2_shown = game.player2_ranking.is_some()
+            && (!running_twice || game.player2_second_ranking.is_some());
+        let forfeiting = match (player1_shown, player2_shown) {
+            (true, false) => 1,
+            (false, true) => 0,
+            _ => game
+                .last_aggressor
+                .unwrap_or(Self::out_of_position(&game)),
+        };
+        let winner_index = 1 - forfeiting;
+        let winner = if winner_index == 0 {
+            game.player1.clone()
+        } else {
+            game.player2.clone()
+        };
+
+        let session_over = Self::finish_hand(&env, &mut game, winner_index);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        if session_over {
+            Self::end_session(&env, session_id, &game);
+        }
 
         Ok(winner)
     }
 
+    /// Void a hand the opponent never committed to
+    ///
+    /// Once the commit deadline passes with only the caller committed, the
+    /// blinds and antes go back to the stacks and the session is closed
+    /// with no winner. In escrow mode both buy-ins are released in full.
+    /// Neither the Game Hub nor a parent tournament is sent a result, since
+    /// neither player won.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player who committed (caller)
+    pub fn reclaim(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != Phase::Commit {
+            return Err(Error::NotInPhase);
+        }
+        let committed = if player == game.player1 {
+            game.player1_hole_commitment.is_some()
+        } else if player == game.player2 {
+            game.player2_hole_commitment.is_some()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if !committed {
+            return Err(Error::NotCommitted);
+        }
+        if env.ledger().sequence() <= game.commit_deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        Self::refund_hand(&mut game);
+
+        game.winner = None;
+        game.phase = Phase::Complete;
+        Self::settle_bounties(&mut game);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::end_session(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Give every chip in the current hand back to the player who put it in
+    ///
+    /// Current-street bets and the side pot go back to their owners; the
+    /// rest of the pot (earlier streets and antes) was matched heads-up and
+    /// is split evenly, odd chip to player1.
+    fn refund_hand(game: &mut Game) {
+        let matched = game.pot - game.player1_bet - game.player2_bet;
+        game.player1_stack += game.player1_bet + matched - matched / 2;
+        game.player2_stack += game.player2_bet + matched / 2;
+        game.player1_bet = 0;
+        game.player2_bet = 0;
+        game.pot = 0;
+        Self::refund_uncalled(game);
+    }
+
+    /// Commitment to the full board: keccak256 over the 5 cards as big-endian u32s
+    fn hash_community_cards(env: &Env, cards: &Vec<u32>) -> Bytes {
+        let mut data = Bytes::new(env);
+        for card in cards.iter() {
+            data.append(&Bytes::from_array(env, &card.to_be_bytes()));
+        }
+        env.crypto().keccak256(&data).into()
+    }
+
+    /// Reveal one player's hand with a ZK proof
+    ///
+    /// Each player proves their own hand independently, so neither controls
+    /// the other's showdown input. Once both hands on a board are proven the
+    /// winner is computed on-chain from the two rankings; equal rankings chop
+    /// the board. A single-hand session whose only hand is chopped ends with
+    /// no winner. When running it twice each player proves a hand for each
+    /// board.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `proof` - Groth16 ZK proof
+    /// * `public_signals` - Public signals from the proof:
+    ///   `[0]` hole commitment, `[1]` board commitment, `[2]` hand ranking
+    ///   (higher is stronger), `[3]` session_id, `[4]` proof_nonce
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Winner of the board once both hands are proven;
+    ///   None while waiting for the other hand or when the board is chopped
+    pub fn reveal_hand(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<Option<Address>, Error> {
+        player.require_auth();
+        Self::apply_hand_reveal(&env, session_id, &player, proof, public_signals)
+    }
+
+    /// Reveal the final hand of an escrow session and pay out in one call
+    ///
+    /// Same as `reveal_hand`, but the proof must be the one that ends the
+    /// session. In the same invocation the escrowed buy-ins are paid back in
+    /// the final stack split, so no separate claim is needed. Sessions
+    /// without an escrow token hold no funds and are rejected.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `proof` - Groth16 ZK proof
+    /// * `public_signals` - Public signals from the proof (see `reveal_hand`)
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Address of the session winner, None if the
+    ///   session ended without one
+    pub fn reveal_hand_and_payout(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<Option<Address>, Error> {
+        player.require_auth();
+
+        // Only escrowed buy-ins can be paid out
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.escrow_token.is_none() {
+            return Err(Error::PayoutNotConfigured);
+        }
+
+        // Ending the session pays each player their final stack
+        Self::apply_hand_reveal(&env, session_id, &player, proof, public_signals)?;
+
+        // Reverts the reveal too unless this proof ended the session
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.phase != Phase::Complete {
+            return Err(Error::SessionNotOver);
+        }
+        Ok(game.winner)
+    }
+
+    /// Verify and record a hand proof, completing the showdown once both hands are in
+    fn apply_hand_reveal(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != Phase::Showdown {
+            return Err(Error::NotInPhase);
+        }
+        let player_index: u32 = if *player == game.player1 {
+            0
+        } else if *player == game.player2 {
+            1
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Each accepted proof can only be processed once per session
+        let nullifiers_key = DataKey::ProofNullifiers(session_id);
+        let mut nullifiers: Vec<BytesN<32>> = env
+            .storage()
+            .temporary()
+            .get(&nullifiers_key)
+            .unwrap_or(Vec::new(env));
+        let proof_hash = Self::proof_hash(env, &proof);
+        if nullifiers.contains(&proof_hash) {
+            return Err(Error::ProofAlreadyUsed);
+        }
+
+        let (ranking, second_board) =
+            Self::verify_hand_reveal(env, session_id, &game, player_index, proof, public_signals)?;
+
+        nullifiers.push_back(proof_hash);
+        env.storage().temporary().set(&nullifiers_key, &nullifiers);
+        env.storage()
+            .temporary()
+            .extend_ttl(&nullifiers_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // Record the ranking for the board this proof was made against
+        let slot = match (second_board, player_index) {
+            (false, 0) => &mut game.player1_ranking,
+            (false, _) => &mut game.player2_ranking,
+            (true, 0) => &mut game.player1_second_ranking,
+            (true, _) => &mut game.player2_second_ranking,
+        };
+        if slot.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        *slot = Some(ranking);
+        if !second_board {
+            if player_index == 0 {
+                game.player1_revealed = true;
+            } else {
+                game.player2_revealed = true;
+            }
+        }
+
+        let rankings = if second_board {
+            (game.player1_second_ranking, game.player2_second_ranking)
+        } else {
+            (game.player1_ranking, game.player2_ranking)
+        };
+        let (p1_ranking, p2_ranking) = match rankings {
+            (Some(p1), Some(p2)) => (p1, p2),
+            _ => {
+                // Waiting for the opponent's proof
+                env.storage().temporary().set(&key, &game);
+                env.storage()
+                    .temporary()
+                    .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+                return Ok(None);
+            }
+        };
+
+        let winner_index = if p1_ranking > p2_ranking {
+            0
+        } else if p2_ranking > p1_ranking {
+            1
+        } else {
+            CHOPPED
+        };
+        if second_board {
+            game.second_board_winner = Some(winner_index);
+        } else if game.second_board_commitment.is_some() {
+            game.first_board_winner = Some(winner_index);
+        }
+        Self::complete_showdown(env, session_id, &mut game, winner_index);
+
+        Ok(match winner_index {
+            0 => Some(game.player1.clone()),
+            1 => Some(game.player2.clone()),
+            _ => None,
+        })
+    }
+
+    /// Nullifier for a proof: keccak256 over its three points
+    fn proof_hash(env: &Env, proof: &Groth16Proof) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.append(&proof.pi_a.clone().into());
+        data.append(&proof.pi_b.clone().into());
+        data.append(&proof.pi_c.clone().into());
+        env.crypto().keccak256(&data).into()
+    }
+
+    /// Verify a single player's hand proof against the game's commitments
+    ///
+    /// # Returns
+    /// * `(u32, bool)` - The hand ranking, and whether it is for the second board
+    fn verify_hand_reveal(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        player_index: u32,
+        proof: Groth16Proof,
+        public_signals: Vec<Bytes>,
+    ) -> Result<(u32, bool), Error> {
+        let hole_commitment = if player_index == 0 {
+            game.player1_hole_commitment.clone()
+        } else {
+            game.player2_hole_commitment.clone()
+        }
+        .ok_or(Error::NotCommitted)?;
+
+        if public_signals.len() < 5 {
+            return Err(Error::InvalidProof);
+        }
+
+        // Bind the proof to this session and hand so it can't be replayed
+        // in another game with identical commitments
+        if Self::bytes_to_u32(&public_signals.get(3).unwrap()) != session_id {
+            return Err(Error::InvalidProof);
+        }
+        if Self::bytes_to_u32(&public_signals.get(4).unwrap()) != game.proof_nonce {
+            return Err(Error::InvalidProof);
+        }
+
+        // The proof must open this player's own hole commitment
+        if public_signals.get(0).unwrap() != hole_commitment {
+            return Err(Error::InvalidCommitment);
+        }
+
+        // ...against one of the dealt boards
+        let board_commitment = Some(public_signals.get(1).unwrap());
+        let second_board = if board_commitment == game.community_commitment {
+            false
+        } else if game.second_board_commitment.is_some()
+            && board_commitment == game.second_board_commitment
+        {
+            true
+        } else {
+            return Err(Error::InvalidCommitment);
+        };
+
+        Self::verify_groth16_proof(env, game.vk_version, proof, public_signals.clone())?;
+
+        let ranking = Self::bytes_to_u32(&public_signals.get(2).unwrap());
+        Ok((ranking, second_board))
+    }
+
+    /// Finish the hand after a showdown proof and notify GameHub if the session ended
+    ///
+    /// When running it twice the hand only finishes once both boards are proven.
+    /// If the boards are split the hand has no single winner and counts as chopped.
+    fn complete_showdown(env: &Env, session_id: u32, game: &mut Game, winner_index: u32) {
+        let hand_winner = if game.second_board_commitment.is_some() {
+            match (game.first_board_winner, game.second_board_winner) {
+                (Some(first), Some(second)) => {
+                    if first == second { first } else { CHOPPED }
+                }
+                _ => {
+                    // Waiting for the other board's proof
+                    let key = DataKey::Game(session_id);
+                    env.storage().temporary().set(&key, game);
+                    env.storage()
+                        .temporary()
+                        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+                    return;
+                }
+            }
+        } else {
+            winner_index
+        };
+
+        // Main pot goes to the winner, side pot back to the player who funded it
+        let session_over = Self::finish_hand(env, game, hand_winner);
+
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        if session_over {
+            Self::end_session(env, session_id, game);
+        }
+    }
+
+    /// Close a finished session
+    ///
+    /// Callers persist the Complete game before calling this, so a failing
+    /// GameHub can't leave the two out of sync: the failure is recorded and
+    /// `retry_end_game` reports the result later.
+    ///
+    /// In escrow mode each player is paid their final stack (rake stays in
+    /// the contract); otherwise the result is reported to GameHub. GameHub
+    /// has no draw outcome, so a session without a winner is not reported,
+    /// as chess does for draws. Sessions that belong to a tournament also
+    /// report the result to its contract.
+    fn end_session(env: &Env, session_id: u32, game: &Game) {
+        if let (Some(parent), Some(winner)) = (&game.parent, &game.winner) {
+            let loser = if *winner == game.player1 { &game.player2 } else { &game.player1 };
+            TournamentClient::new(env, &parent.contract).report_result(
+                &parent.tournament_id,
+                &session_id,
+                winner,
+                loser,
+            );
+        }
+
+        if let Some(escrow_token) = &game.escrow_token {
+            let token = token::TokenClient::new(env, escrow_token);
+            let contract = env.current_contract_address();
+            if game.player1_stack > 0 {
+                token.transfer(&contract, &game.player1, &game.player1_stack);
+            }
+            if game.player2_stack > 0 {
+                token.transfer(&contract, &game.player2, &game.player2_stack);
+            }
+            return;
+        }
+
+        let player1_won = match &game.winner {
+            Some(winner) => *winner == game.player1,
+            None => return,
+        };
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        if !matches!(game_hub.try_end_game(&session_id, &player1_won), Ok(Ok(_))) {
+            let pending_key = DataKey::HubPending(session_id);
+            env.storage().temporary().set(&pending_key, &true);
+            env.storage()
+                .temporary()
+                .extend_ttl(&pending_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+
+    /// Report a completed session to GameHub after its end_game call failed
+    ///
+    /// Anyone can call this; the result reported is the one already stored.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn retry_end_game(env: Env, session_id: u32) -> Result<(), Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != Phase::Complete {
+            return Err(Error::NotInPhase);
+        }
+        let pending_key = DataKey::HubPending(session_id);
+        if !env.storage().temporary().has(&pending_key) {
+            return Err(Error::GameAlreadyEnded);  // GameHub already has the result
+        }
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        // Only sessions with a winner are ever marked pending
+        let player1_won = game.winner == Some(game.player1.clone());
+        game_hub.end_game(&session_id, &player1_won);
+
+        env.storage().temporary().remove(&pending_key);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -840,20 +2728,340 @@ impl PockerContract {
             .ok_or(Error::GameNotFound)
     }
 
+    /// Extend the TTL of a game and its per-session keys without changing them
+    /// Lets long-running games stay alive past the 30-day window.
+    ///
+    /// Extends the game, its schema version, proof nullifiers, pending hub
+    /// report and force-end, both players' session indexes, and a page of up
+    /// to 50 history entries from `history_start`; longer histories take
+    /// further calls with later starts.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Either player of the session (caller)
+    /// * `history_start` - Index of the first history entry to extend
+    pub fn extend_game_ttl(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        history_start: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        let history_len: u32 = env
+            .storage()
+            .temporary()
+            .get(&DataKey::HistoryLen(session_id))
+            .unwrap_or(0);
+        let history_end = history_start.saturating_add(MAX_HISTORY_PAGE).min(history_len);
+
+        let mut aux_keys = vec![
+            &env,
+            DataKey::GameSchema(session_id),
+            DataKey::HistoryLen(session_id),
+            DataKey::ProofNullifiers(session_id),
+            DataKey::HubPending(session_id),
+            DataKey::ForceEnd(session_id),
+            DataKey::PlayerSessions(game.player1),
+            DataKey::PlayerSessions(game.player2),
+        ];
+        for index in history_start..history_end {
+            aux_keys.push_back(DataKey::HistoryEntry(session_id, index));
+        }
+        for aux_key in aux_keys.iter() {
+            if env.storage().temporary().has(&aux_key) {
+                env.storage()
+                    .temporary()
+                    .extend_ttl(&aux_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get a compact summary of the game for polling UIs
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `GameSummary` - Phase, pot, stacks, current actor and winner
+    pub fn get_summary(env: Env, session_id: u32) -> Result<GameSummary, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        Ok(GameSummary {
+            phase: game.phase,
+            pot: game.pot,
+            player1_stack: game.player1_stack,
+            player2_stack: game.player2_stack,
+            current_actor: game.current_actor,
+            winner: game.winner,
+        })
+    }
+
+    /// Get just the phase of a game, for clients polling every ledger
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Phase` - The current phase
+    pub fn get_phase(env: Env, session_id: u32) -> Result<Phase, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        Ok(game.phase)
+    }
+
+    /// Get just the seat whose turn it is, for clients polling every ledger
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - 0 for player1, 1 for player2
+    pub fn get_current_actor(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        Ok(game.current_actor)
+    }
+
+    /// Get a digest of the full game state
+    /// Players and spectators compare digests to confirm they render the
+    /// same state without each downloading the whole `Game`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - keccak256 of the game's XDR encoding
+    pub fn get_state_digest(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        Ok(env.crypto().keccak256(&game.to_xdr(&env)).into())
+    }
+
+    /// Get the table listing for a session
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `TableMetadata` - Table name, current blinds, structure and hand count
+    pub fn get_table_metadata(env: Env, session_id: u32) -> Result<TableMetadata, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        Ok(TableMetadata {
+            name: game.table_name,
+            small_blind: game.small_blind,
+            big_blind: game.big_blind,
+            ante: game.ante,
+            blind_level: game.blind_level,
+            structure: game.structure,
+            max_hands: game.max_hands,
+            hand_number: game.hand_number,
+        })
+    }
+
+    /// Get a page of the session's betting history
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `start` - Index of the first entry to return
+    /// * `limit` - Maximum entries to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `Vec<HistoryEntry>` - Entries in the order they were played
+    pub fn get_history(env: Env, session_id: u32, start: u32, limit: u32) -> Vec<HistoryEntry> {
+        let len: u32 = env
+            .storage()
+            .temporary()
+            .get(&DataKey::HistoryLen(session_id))
+            .unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_HISTORY_PAGE)).min(len);
+
+        let mut entries = Vec::new(&env);
+        for index in start..end {
+            if let Some(entry) = env
+                .storage()
+                .temporary()
+                .get::<_, HistoryEntry>(&DataKey::HistoryEntry(session_id, index))
+            {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    /// Get the number of entries in the session's betting history
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - Total history entries
+    pub fn get_history_len(env: Env, session_id: u32) -> u32 {
+        env.storage()
+            .temporary()
+            .get(&DataKey::HistoryLen(session_id))
+            .unwrap_or(0)
+    }
+
+    /// Get a player's lifetime statistics
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    ///
+    /// # Returns
+    /// * `PlayerStats` - Hands played and won, total pot won and folds (zeros if none)
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerStats(player))
+            .unwrap_or_default()
+    }
+
+    /// Get the sessions a player has started recently
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - Session IDs, oldest first (empty if none)
+    pub fn get_player_sessions(env: Env, player: Address) -> Vec<u32> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::PlayerSessions(player))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Card Encoding
+    // ========================================================================
+
+    /// Decode a card from the 0-51 encoding used by the contract and circuits
+    ///
+    /// # Arguments
+    /// * `card` - Encoded card (0-51)
+    ///
+    /// # Returns
+    /// * `Card` - Rank (0 = Two ... 12 = Ace) and suit (0-3)
+    pub fn card_to_rank_suit(card: u32) -> Result<Card, Error> {
+        if card >= DECK_SIZE {
+            return Err(Error::InvalidCard);
+        }
+        Ok(Card {
+            rank: card % RANKS_PER_SUIT,
+            suit: card / RANKS_PER_SUIT,
+        })
+    }
+
+    /// Decode a hand ranking from a showdown proof
+    ///
+    /// # Arguments
+    /// * `ranking` - Ranking signal (0-9)
+    ///
+    /// # Returns
+    /// * `HandRank` - The named hand category
+    pub fn hand_rank(ranking: u32) -> Result<HandRank, Error> {
+        Ok(match ranking {
+            0 => HandRank::HighCard,
+            1 => HandRank::OnePair,
+            2 => HandRank::TwoPair,
+            3 => HandRank::ThreeOfAKind,
+            4 => HandRank::Straight,
+            5 => HandRank::Flush,
+            6 => HandRank::FullHouse,
+            7 => HandRank::FourOfAKind,
+            8 => HandRank::StraightFlush,
+            9 => HandRank::RoyalFlush,
+            _ => return Err(Error::InvalidHandRank),
+        })
+    }
+
     // ========================================================================
     // ZK Proof Verification (Protocol 25)
     // ========================================================================
 
+    /// Compute a hole commitment exactly as the hand reveal circuit does:
+    /// Poseidon(3) over the 2 hole cards followed by the salt, on BN254
+    ///
+    /// `reveal_hand` requires the proof's public hole commitment to equal the
+    /// bytes passed to `submit_hole_commitment`, so this is the value to submit.
+    ///
+    /// # Arguments
+    /// * `cards` - The 2 hole cards being committed to (0-51)
+    /// * `salt` - Secret salt (must be a BN254 scalar field element)
+    ///
+    /// # Returns
+    /// * `Bytes` - 32-byte big-endian commitment, as passed to `submit_hole_commitment`
+    pub fn compute_commitment(env: Env, cards: Vec<u32>, salt: U256) -> Result<Bytes, Error> {
+        if cards.len() != 2 {
+            return Err(Error::InvalidCommitment);
+        }
+
+        let mut inputs: Vec<U256> = Vec::new(&env);
+        for card in cards.iter() {
+            if card >= DECK_SIZE {
+                return Err(Error::InvalidCommitment);
+            }
+            inputs.push_back(U256::from_u32(&env, card));
+        }
+        inputs.push_back(salt);
+
+        let hash = poseidon_hash::<4, BnScalar>(&env, &inputs);
+        Ok(hash.to_be_bytes())
+    }
+
     /// Verify a Groth16 ZK proof using Protocol 25 BN254 operations
+    /// against the verification key version the game was started with
     fn verify_groth16_proof(
         env: &Env,
+        vk_version: u32,
         proof: Groth16Proof,
         public_signals: Vec<Bytes>,
     ) -> Result<(), Error> {
+        if Self::is_dev_mode(env) {
+            return Ok(());
+        }
+
         let vk: VerificationKey = env
             .storage()
             .instance()
-            .get(&DataKey::VerificationKey)
+            .get(&DataKey::VerificationKey(vk_version))
             .ok_or(Error::InvalidProof)?;
 
         let verifier_proof = VerifierProof {
@@ -872,6 +3080,21 @@ impl PockerContract {
         Ok(())
     }
 
+    /// Whether the ledger is running on a known test network
+    fn is_test_network(env: &Env) -> bool {
+        let network_id = env.ledger().network_id();
+        TEST_NETWORK_PASSPHRASES.iter().any(|passphrase| {
+            let hash: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(env, passphrase)).into();
+            hash == network_id
+        })
+    }
+
+    /// Whether proof verification is skipped (never outside a test network)
+    fn is_dev_mode(env: &Env) -> bool {
+        env.storage().instance().get(&DataKey::DevMode).unwrap_or(false)
+            && Self::is_test_network(env)
+    }
+
     /// Convert Bytes to u32 (helper function)
     /// CRITICAL FIX #2: Use big-endian interpretation to match ZK circuit output format
     fn bytes_to_u32(bytes: &Bytes) -> u32 {
@@ -946,10 +3169,13 @@ impl PockerContract {
     }
 
     /// Set the verification key for ZK proof verification
+    /// The version becomes current for newly started games; in-flight games
+    /// keep verifying against the version they started with.
     ///
     /// # Arguments
+    /// * `version` - Version number for this key (must be non-zero)
     /// * `vk` - The verification key from trusted setup
-    pub fn set_verification_key(env: Env, vk: VerificationKey) {
+    pub fn set_verification_key(env: Env, version: u32, vk: VerificationKey) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
@@ -957,19 +3183,502 @@ impl PockerContract {
             .expect("Admin not set");
         admin.require_auth();
 
+        if version == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::VerificationKey(version), &vk);
         env.storage()
             .instance()
-            .set(&DataKey::VerificationKey, &vk);
+            .set(&DataKey::CurrentVkVersion, &version);
+
+        Ok(())
     }
 
-    /// Get the current verification key
+    /// Get a verification key by version
+    ///
+    /// # Arguments
+    /// * `version` - The verification key version
     ///
     /// # Returns
     /// * `VerificationKey` - The verification key
-    pub fn get_verification_key(env: Env) -> Option<VerificationKey> {
+    pub fn get_verification_key(env: Env, version: u32) -> Option<VerificationKey> {
+        env.storage()
+            .instance()
+            .get(&DataKey::VerificationKey(version))
+    }
+
+    /// Get the verification key version used by newly started games
+    ///
+    /// # Returns
+    /// * `u32` - The current version (0 if no key has been set)
+    pub fn get_current_vk_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CurrentVkVersion)
+            .unwrap_or(0)
+    }
+
+    /// Configure the rake taken from every completed hand
+    ///
+    /// # Arguments
+    /// * `rake_bps` - Rake in basis points (max 10%)
+    /// * `treasury` - Address entitled to the collected rake
+    pub fn set_rake(env: Env, rake_bps: u32, treasury: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if rake_bps > MAX_RAKE_BPS {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage().instance().set(&DataKey::RakeBps, &rake_bps);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+
+        Ok(())
+    }
+
+    /// Get the current rake in basis points
+    ///
+    /// # Returns
+    /// * `u32` - The rake (0 if not configured)
+    pub fn get_rake_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RakeBps)
+            .unwrap_or(0)
+    }
+
+    /// Get the treasury address
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The treasury address, if configured
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+    }
+
+    /// Get the rake collected in a token since the last withdrawal
+    ///
+    /// # Arguments
+    /// * `token` - Escrow token the rake was collected in
+    ///
+    /// # Returns
+    /// * `i128` - The accumulated rake
+    pub fn get_accumulated_rake(env: Env, token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AccumulatedRake(token))
+            .unwrap_or(0)
+    }
+
+    /// Transfer the rake accumulated in a token to the treasury
+    ///
+    /// # Arguments
+    /// * `token` - Escrow token the rake was collected in
+    ///
+    /// # Returns
+    /// * `i128` - The amount withdrawn
+    pub fn withdraw_rake(env: Env, token: Address) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(Error::InvalidConfig)?;
+
+        let rake_key = DataKey::AccumulatedRake(token.clone());
+        let amount: i128 = env
+            .storage()
+            .instance()
+            .get(&rake_key)
+            .unwrap_or(0);
+        if amount > 0 {
+            env.storage().instance().set(&rake_key, &0i128);
+            token::TokenClient::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &treasury,
+                &amount,
+            );
+        }
+
+        Ok(amount)
+    }
+
+    /// Schedule an admin force_end for a game that can't finish normally
+    ///
+    /// For stuck sessions (e.g. a circuit bug or missing verification key).
+    /// The outcome can only be applied after `FORCE_END_DELAY_LEDGERS`, giving
+    /// players time to see the event and finish the game themselves.
+    /// Proposing again replaces the outcome and restarts the delay.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `outcome` - The result to impose
+    ///
+    /// # Returns
+    /// * `u32` - Ledger sequence from which `force_end` may run
+    pub fn propose_force_end(env: Env, session_id: u32, outcome: ForceOutcome) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.phase == Phase::Complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let executable_at = env.ledger().sequence() + FORCE_END_DELAY_LEDGERS;
+        let key = DataKey::ForceEnd(session_id);
+        env.storage()
+            .temporary()
+            .set(&key, &PendingForceEnd { outcome, executable_at });
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        env.events().publish(
+            (symbol_short!("FE_PROP"), session_id),
+            (outcome, executable_at)
+        );
+
+        Ok(executable_at)
+    }
+
+    /// Apply a scheduled force_end once its delay has passed
+    ///
+    /// A forced win awards the current pot to that player; a draw returns
+    /// the hand's chips to both. The session then ends as usual (escrow
+    /// payout or GameHub report). A drawn session goes to the bigger stack;
+    /// with equal stacks it ends without a winner and GameHub is not told.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `outcome` - Must match the proposed outcome
+    pub fn force_end(env: Env, session_id: u32, outcome: ForceOutcome) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if game.phase == Phase::Complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let force_key = DataKey::ForceEnd(session_id);
+        let pending: PendingForceEnd = env
+            .storage()
+            .temporary()
+            .get(&force_key)
+            .ok_or(Error::InvalidConfig)?;
+        if pending.outcome != outcome {
+            return Err(Error::InvalidConfig);
+        }
+        if env.ledger().sequence() < pending.executable_at {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        game.winner = match outcome {
+            ForceOutcome::Player1Wins => {
+                Self::settle_pots(&mut game, 0);
+                Some(game.player1.clone())
+            }
+            ForceOutcome::Player2Wins => {
+                Self::settle_pots(&mut game, 1);
+                Some(game.player2.clone())
+            }
+            ForceOutcome::Draw => {
+                Self::refund_hand(&mut game);
+                if game.player1_stack > game.player2_stack {
+                    Some(game.player1.clone())
+                } else if game.player2_stack > game.player1_stack {
+                    Some(game.player2.clone())
+                } else {
+                    None
+                }
+            }
+        };
+        game.phase = Phase::Complete;
+        Self::settle_bounties(&mut game);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        env.storage().temporary().remove(&force_key);
+
+        env.events().publish((symbol_short!("FORCEEND"), session_id), outcome);
+
+        Self::end_session(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Enable or disable dev mode, which skips Groth16 verification
+    /// Lets frontends integrate before circuits are final; refused unless
+    /// the contract runs on testnet, futurenet or a standalone network.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to skip proof verification
+    pub fn set_dev_mode(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if enabled && !Self::is_test_network(&env) {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage().instance().set(&DataKey::DevMode, &enabled);
+
+        Ok(())
+    }
+
+    /// Check if dev mode is active
+    ///
+    /// # Returns
+    /// * `bool` - True if proofs are currently not verified
+    pub fn get_dev_mode(env: Env) -> bool {
+        Self::is_dev_mode(&env)
+    }
+
+    /// Pause new games and betting actions
+    pub fn pause(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &true);
+    }
+
+    /// Resume new games and betting actions
+    pub fn unpause(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &false);
+    }
+
+    /// Check whether the contract is paused
+    ///
+    /// # Returns
+    /// * `bool` - True if paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    /// Set the accepted buy-in range for new games
+    ///
+    /// # Arguments
+    /// * `min_buy_in` - Smallest buy-in per player (must be positive)
+    /// * `max_buy_in` - Largest buy-in per player
+    pub fn set_buy_in_bounds(env: Env, min_buy_in: i128, max_buy_in: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if min_buy_in <= 0 || max_buy_in < min_buy_in {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage().instance().set(&DataKey::MinBuyIn, &min_buy_in);
+        env.storage().instance().set(&DataKey::MaxBuyIn, &max_buy_in);
+
+        Ok(())
+    }
+
+    /// Get the accepted buy-in range
+    ///
+    /// # Returns
+    /// * `(i128, i128)` - Minimum and maximum buy-in (defaults to 1 and unbounded)
+    pub fn get_buy_in_bounds(env: Env) -> (i128, i128) {
+        let min_buy_in: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinBuyIn)
+            .unwrap_or(1);
+        let max_buy_in: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxBuyIn)
+            .unwrap_or(i128::MAX);
+        (min_buy_in, max_buy_in)
+    }
+
+    /// Get the storage schema version of the deployed code
+    ///
+    /// # Returns
+    /// * `u32` - The schema version
+    pub fn get_schema_version(env: Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::VerificationKey)
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(GAME_SCHEMA_VERSION)
+    }
+
+    /// Migrate an in-flight game to the current storage layout after an upgrade
+    ///
+    /// Games without a recorded version predate versioning and use layout 1
+    /// (`GameV1`). Each layout change keeps the previous struct and adds a
+    /// branch here that decodes it and rewrites the game in the new layout.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - The game's schema version after migration
+    pub fn migrate(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game_key = DataKey::Game(session_id);
+        if !env.storage().temporary().has(&game_key) {
+            return Err(Error::GameNotFound);
+        }
+
+        let schema_key = DataKey::GameSchema(session_id);
+        let version: u32 = env.storage().temporary().get(&schema_key).unwrap_or(1);
+
+        match version {
+            1 => {
+                let old: GameV1 = env.storage().temporary().get(&game_key).unwrap();
+                let game = Self::game_from_v1(&env, old);
+                env.storage().temporary().set(&game_key, &game);
+                env.storage()
+                    .temporary()
+                    .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            }
+            GAME_SCHEMA_VERSION => {}
+            _ => return Err(Error::UnsupportedSchema),
+        }
+
+        env.storage().temporary().set(&schema_key, &GAME_SCHEMA_VERSION);
+        env.storage()
+            .temporary()
+            .extend_ttl(&schema_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        env.storage().instance().set(&DataKey::SchemaVersion, &GAME_SCHEMA_VERSION);
+
+        Ok(GAME_SCHEMA_VERSION)
+    }
+
+    /// Rewrite a layout 1 game with the fields added since at their defaults
+    ///
+    /// Layout 1 games were single-hand, blind-free, no-limit hub sessions
+    /// verified against the one unversioned key, so they move to the current
+    /// key version and get a fresh proof nonce for their showdown.
+    fn game_from_v1(env: &Env, old: GameV1) -> Game {
+        Game {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            player1_stack: old.player1_stack,
+            player2_stack: old.player2_stack,
+            player1_bet: old.player1_bet,
+            player2_bet: old.player2_bet,
+            pot: old.pot,
+            side_pot: 0,
+            side_pot_owner: 0,
+            small_blind: 0,
+            big_blind: 0,
+            ante: 0,
+            dealer: 0,
+            hand_number: 1,
+            max_hands: 1,
+            tournament: None,
+            blind_level: 0,
+            player1_hole_commitment: old.player1_hole_commitment,
+            player2_hole_commitment: old.player2_hole_commitment,
+            community_cards: old.community_cards,
+            community_commitment: old.community_commitment,
+            community_revealed: old.community_revealed,
+            current_actor: old.current_actor,
+            last_action: old.last_action,
+            last_raise_amount: old.last_raise_amount,
+            actions_this_round: old.actions_this_round,
+            raises_this_round: 0,
+            last_aggressor: None,
+            raise_cap: 0,
+            betting_reopened: true,
+            structure: BettingStructure::NoLimit,
+            player1_revealed: old.player1_revealed,
+            player2_revealed: old.player2_revealed,
+            player1_ranking: old.player1_ranking,
+            player2_ranking: old.player2_ranking,
+            player1_second_ranking: None,
+            player2_second_ranking: None,
+            winner: old.winner,
+            phase: old.phase,
+            vk_version: env
+                .storage()
+                .instance()
+                .get(&DataKey::CurrentVkVersion)
+                .unwrap_or(0),
+            proof_nonce: Self::next_proof_nonce(env),
+            showdown_deadline: None,
+            commit_deadline: env.ledger().sequence() + COMMIT_TIMEOUT_LEDGERS,
+            player1_run_twice: false,
+            player2_run_twice: false,
+            second_board: Vec::new(env),
+            second_board_commitment: None,
+            first_board_winner: None,
+            second_board_winner: None,
+            agreed_split_bps: None,
+            player1_sitting_out: false,
+            player2_sitting_out: false,
+            player1_missed_hands: 0,
+            player2_missed_hands: 0,
+            table_name: None,
+            escrow_token: None,
+            player1_bounty: 0,
+            player2_bounty: 0,
+            bounty_winner: None,
+            parent: None,
+            max_rebuys: 0,
+            player1_rebuys: 0,
+            player2_rebuys: 0,
+        }
     }
 
     /// Update the contract WASM hash (upgrade contract)
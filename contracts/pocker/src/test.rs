@@ -2,8 +2,8 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
-    Address, Env, IntoVal, Symbol, Vec,
+    testutils::{storage::Temporary as _, Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger},
+    Address, Env, IntoVal, Symbol, Val, Vec,
 };
 
 // Mock Game Hub contract for testing
@@ -13,12 +13,65 @@ mod mock_game_hub {
     );
 }
 
+// Minimal parent tournament that records the results reported to it
+mod mock_tournament {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct MockTournament;
+
+    #[contractimpl]
+    impl MockTournament {
+        pub fn report_result(
+            env: Env,
+            tournament_id: u32,
+            session_id: u32,
+            winner: Address,
+            loser: Address,
+        ) {
+            env.storage().instance().set(&session_id, &(tournament_id, winner, loser));
+        }
+
+        pub fn result(env: Env, session_id: u32) -> Option<(u32, Address, Address)> {
+            env.storage().instance().get(&session_id)
+        }
+    }
+}
+
 fn create_pocker_contract<'a>(e: &Env) -> (Address, PockerContractClient<'a>) {
     let contract_id = e.register_contract(None, PockerContract);
     let client = PockerContractClient::new(e, &contract_id);
     (contract_id, client)
 }
 
+fn default_config() -> TableConfig {
+    TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 1,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    }
+}
+
+fn dummy_verification_key(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha: BytesN::from_array(env, &[0u8; 64]),
+        beta: BytesN::from_array(env, &[0u8; 128]),
+        gamma: BytesN::from_array(env, &[0u8; 128]),
+        delta: BytesN::from_array(env, &[0u8; 128]),
+        ic: Vec::new(env),
+    }
+}
+
 fn create_game_hub<'a>(e: &Env) -> (Address, mock_game_hub::Client<'a>) {
     let contract_id = e.register_contract_wasm(None, mock_game_hub::WASM);
     let client = mock_game_hub::Client::new(e, &contract_id);
@@ -68,6 +121,7 @@ fn test_start_game() {
         &player2,
         &player1_points,
         &player2_points,
+        &default_config(),
     );
 
     // Verify game was created
@@ -76,6 +130,7 @@ fn test_start_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, player1_points);
     assert_eq!(game.player2_points, player2_points);
+    assert_eq!(game.pot, 3); // SB + BB posted
     assert_eq!(game.phase, Phase::Commit);
     assert!(game.winner.is_none());
 
@@ -94,7 +149,7 @@ fn test_start_game() {
                             player1.clone(),
                             player2.clone(),
                             player1_points,
-                            player2_points,
+                            default_config(),
                         )
                             .into_val(&env)
                     )),
@@ -120,7 +175,74 @@ fn test_prevent_self_play() {
     game_hub.initialize(&admin);
 
     // Try to start game with same player
-    pocker.start_game(&1u32, &player, &player, &100i128, &100i128);
+    pocker.start_game(&1u32, &player, &player, &100i128, &100i128, &default_config());
+}
+
+#[test]
+fn test_blinds_and_dealer_button() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // Even session: player1 has the button and posts the small blind
+    pocker.start_game(&2u32, &player1, &player2, &100i128, &100i128, &default_config());
+    let game = pocker.get_game(&2u32).unwrap();
+    assert_eq!(game.dealer, 0);
+    assert_eq!(game.player1_stack, 99);
+    assert_eq!(game.player2_stack, 98);
+    assert_eq!(game.player1_bet, 1);
+    assert_eq!(game.player2_bet, 2);
+    assert_eq!(game.pot, 3);
+    assert_eq!(game.current_actor, 0);
+
+    // Odd session: the button moves to player2
+    pocker.start_game(&3u32, &player1, &player2, &100i128, &100i128, &default_config());
+    let game = pocker.get_game(&3u32).unwrap();
+    assert_eq!(game.dealer, 1);
+    assert_eq!(game.player1_stack, 98);
+    assert_eq!(game.player2_stack, 99);
+    assert_eq!(game.current_actor, 1);
+}
+
+#[test]
+#[should_panic(expected = "InvalidBlinds")]
+fn test_invalid_blinds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let config = TableConfig {
+        small_blind: 5,
+        big_blind: 2,
+        max_hands: 1,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &config);
 }
 
 #[test]
@@ -140,7 +262,7 @@ fn test_commit_phase() {
     let session_id = 1u32;
 
     // Start game
-    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128);
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
 
     // Player 1 commits hole cards (2 cards)
     let commitment1 = Bytes::from_slice(
@@ -152,6 +274,7 @@ fn test_commit_phase() {
     let game = pocker.get_game(&session_id).unwrap();
     assert!(game.player1_hole_commitment.is_some());
     assert!(game.player2_hole_commitment.is_none());
+    assert!(game.community_commitment.is_none());
     assert_eq!(game.phase, Phase::Commit);
 
     // Player 2 commits hole cards (2 cards)
@@ -165,11 +288,11 @@ fn test_commit_phase() {
     assert!(game.player1_hole_commitment.is_some());
     assert!(game.player2_hole_commitment.is_some());
     assert_eq!(game.phase, Phase::Preflop); // Should move to Preflop phase
+    assert_eq!(game.community_commitment.unwrap().len(), 32);
 }
 
 #[test]
-#[should_panic(expected = "AlreadyCommitted")]
-fn test_cannot_commit_twice() {
+fn test_community_card_flow() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -182,18 +305,49 @@ fn test_cannot_commit_twice() {
     pocker.__constructor(&admin, &game_hub_id);
     game_hub.initialize(&admin);
 
-    let session_id = 1u32;
-    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128);
+    // Even session: player1 is the dealer and acts first preflop
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
 
-    let commitment = Bytes::from_slice(&env, b"commitment_hash");
-    pocker.submit_hole_commitment(&session_id, &player1, &commitment);
+    // Flop cannot be dealt until preflop betting closes
+    assert!(pocker.try_deal_flop(&session_id).is_err());
 
-    // Try to commit again
-    pocker.submit_hole_commitment(&session_id, &player1, &commitment);
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    assert_eq!(pocker.get_game(&session_id).unwrap().phase, Phase::Flop);
+
+    // No betting on the flop before it is dealt
+    assert!(pocker.try_player_action(&session_id, &player1, &Action::Check).is_err());
+
+    let board = pocker.deal_flop(&session_id);
+    assert_eq!(board.len(), 3);
+    assert!(pocker.try_deal_flop(&session_id).is_err());
+
+    // Postflop the big blind (player2) acts first, the dealer is in position
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    assert_eq!(pocker.deal_turn(&session_id).len(), 4);
+
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    let board = pocker.deal_river(&session_id);
+    assert_eq!(board.len(), 5);
+    // Later streets extend the earlier board
+    assert_eq!(board.get(0), pocker.get_game(&session_id).unwrap().community_cards.get(0));
+
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Showdown);
+    assert_eq!(game.community_revealed, 5);
+    assert_eq!(game.pot, 4);
 }
 
 #[test]
-fn test_reveal_winner() {
+fn test_side_pot_for_short_all_in() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -206,57 +360,105 @@ fn test_reveal_winner() {
     pocker.__constructor(&admin, &game_hub_id);
     game_hub.initialize(&admin);
 
-    let session_id = 1u32;
-    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128);
+    // Player1 (dealer) has the bigger stack
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &50i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
 
-    // Both players commit hole cards
-    let commitment1 = Bytes::from_slice(&env, b"commitment1_hash");
-    let commitment2 = Bytes::from_slice(&env, b"commitment2_hash");
-    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
-    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::AllIn);
 
-    // Create mock proof (in real game, this would be a valid ZK proof)
-    let proof_pi_a = BytesN::from_array(&env, &[0u8; 64]);
-    let proof_pi_b = BytesN::from_array(&env, &[1u8; 128]);
-    let proof_pi_c = BytesN::from_array(&env, &[2u8; 64]);
+    // Uncalled 50 is refunded to player1 when the round closes
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.pot, 100);
+    assert_eq!(game.side_pot, 0);
+    assert_eq!(game.player1_stack, 50);
+    assert_eq!(game.player2_stack, 0);
+    assert_eq!(game.phase, Phase::Flop);
+}
 
-    let proof = Groth16Proof {
-        pi_a: proof_pi_a,
-        pi_b: proof_pi_b,
-        pi_c: proof_pi_c,
+#[test]
+fn test_multi_hand_match() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 2,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
     };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
 
-    // Create public signals
-    // [0] = player1_hole_commitment
-    // [1] = player2_hole_commitment
-    // [2] = community_commitment
-    // [3] = player1_ranking (e.g., 5 = Flush)
-    // [4] = player2_ranking (e.g., 3 = Three of a Kind)
-    // [5] = winner (1 = player1)
-    let mut public_signals = Vec::new(&env);
-    public_signals.push_back(commitment1.clone());
-    public_signals.push_back(commitment2.clone());
-    public_signals.push_back(Bytes::from_slice(&env, b"community_commitment"));
-    public_signals.push_back(Bytes::from_slice(&env, &[5u8])); // player1 ranking
-    public_signals.push_back(Bytes::from_slice(&env, &[3u8])); // player2 ranking
-    public_signals.push_back(Bytes::from_slice(&env, &[1u8])); // winner = player1
+    // Hand 1: player1 (dealer, SB) folds preflop
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
 
-    // Note: This will fail without a valid verification key
-    // In production, you would set the verification key first
-    // For this test, we're just verifying the flow structure
-    
-    // Uncomment when verification key is set:
-    // let winner = pocker.reveal_winner(&session_id, &proof, &public_signals);
-    // assert_eq!(winner, player1);
-    
-    // let game = pocker.get_game(&session_id).unwrap();
-    // assert_eq!(game.winner, Some(player1));
-    // assert_eq!(game.phase, Phase::Complete);
+    // Stacks carry over, button moves, blinds posted, back to Commit
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Commit);
+    assert_eq!(game.hand_number, 2);
+    assert_eq!(game.dealer, 1);
+    assert!(game.winner.is_none());
+    assert!(game.player1_hole_commitment.is_none());
+    assert_eq!(game.player1_stack, 97); // 99 - BB
+    assert_eq!(game.player2_stack, 100); // 101 - SB
+    assert_eq!(game.pot, 3);
+
+    // Hand 2 (last): player2 calls, player1 folds
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h2c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h2c2"));
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    // Hand limit reached: bigger stack wins the session
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 97);
+    assert_eq!(game.player2_stack, 103);
+    assert_eq!(game.winner, Some(player2.clone()));
+
+    // Each player takes one hand back: equal stacks, no winner for the hub
+    let session_id = 3u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h2c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h2c2"));
+    pocker.player_action(&session_id, &player2, &Action::Fold);
+
+    assert_eq!(env.events().all(), Vec::<(Address, Vec<Val>, Val)>::new(&env));
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(game.winner, None);
 }
 
 #[test]
-#[should_panic(expected = "NotInPhase")]
-fn test_cannot_reveal_before_commit() {
+fn test_pot_limit_caps_raises() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -269,40 +471,2268 @@ fn test_cannot_reveal_before_commit() {
     pocker.__constructor(&admin, &game_hub_id);
     game_hub.initialize(&admin);
 
-    let session_id = 1u32;
-    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128);
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 1,
+        structure: BettingStructure::PotLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
 
-    // Try to reveal without committing
-    let proof = Groth16Proof {
-        pi_a: Vec::new(&env),
-        pi_b: Vec::new(&env),
-        pi_c: Vec::new(&env),
+    // Pot is 3, SB calls 1 more -> max raise to 2 + 3 + 1 = 6
+    assert!(pocker.try_player_action(&session_id, &player1, &Action::Raise(7)).is_err());
+    assert!(pocker.try_player_action(&session_id, &player1, &Action::AllIn).is_err());
+    pocker.player_action(&session_id, &player1, &Action::Raise(6));
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player1_bet, 6);
+    assert_eq!(game.pot, 8);
+}
+
+#[test]
+fn test_fixed_limit_sizes_and_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 1,
+        structure: BettingStructure::FixedLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
     };
-    let public_signals = Vec::new(&env);
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Preflop raises are exactly one small bet (2)
+    assert!(pocker.try_player_action(&session_id, &player1, &Action::Raise(6)).is_err());
+    pocker.player_action(&session_id, &player1, &Action::Raise(4));
+    pocker.player_action(&session_id, &player2, &Action::Raise(6));
+    pocker.player_action(&session_id, &player1, &Action::Raise(8));
+    pocker.player_action(&session_id, &player2, &Action::Raise(10));
 
-    pocker.reveal_winner(&session_id, &proof, &public_signals);
+    // Fifth bet in the round is capped
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Raise(12)),
+        Err(Ok(Error::RaiseCapReached))
+    );
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    assert_eq!(pocker.get_game(&session_id).unwrap().phase, Phase::Flop);
 }
 
 #[test]
-fn test_admin_functions() {
+fn test_configurable_raise_cap() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let new_admin = Address::generate(&env);
-    let (game_hub_id, _game_hub) = create_game_hub(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
     let (_contract_id, pocker) = create_pocker_contract(&env);
 
     pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
 
-    // Test set_admin
-    pocker.set_admin(&new_admin);
-    assert_eq!(pocker.get_admin(), new_admin);
+    // No-limit table capped at two bets + raises per round
+    let mut config = default_config();
+    config.raise_cap = 2;
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
 
-    // Test set_hub
-    let new_hub = Address::generate(&env);
-    pocker.set_hub(&new_hub);
-    assert_eq!(pocker.get_hub(), new_hub);
+    pocker.player_action(&session_id, &player1, &Action::Raise(4));
+    pocker.player_action(&session_id, &player2, &Action::Raise(8));
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Raise(16)),
+        Err(Ok(Error::RaiseCapReached))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::AllIn),
+        Err(Ok(Error::RaiseCapReached))
+    );
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    assert_eq!(pocker.get_game(&session_id).unwrap().phase, Phase::Flop);
+}
+
+#[test]
+fn test_betting_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Player1 (SB) acts first preflop
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player2, &Action::Check),
+        Err(Ok(Error::NotYourTurn))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Check),
+        Err(Ok(Error::CannotCheck))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Raise(3)),
+        Err(Ok(Error::InvalidBetSize))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Raise(500)),
+        Err(Ok(Error::InsufficientStack))
+    );
+}
+
+#[test]
+fn test_short_all_in_does_not_reopen_betting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &9i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Player1 raises to 6 (a raise of 4); player2 shoves for 9, short of a full raise
+    pocker.player_action(&session_id, &player1, &Action::Raise(6));
+    pocker.player_action(&session_id, &player2, &Action::AllIn);
+    assert!(!pocker.get_game(&session_id).unwrap().betting_reopened);
+
+    // Player1 already acted, so only call or fold remain
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Raise(20)),
+        Err(Ok(Error::InvalidBetSize))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::AllIn),
+        Err(Ok(Error::InvalidBetSize))
+    );
+    pocker.player_action(&session_id, &player1, &Action::Call);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player1_stack, 91);
+    assert!(game.betting_reopened);
+}
+
+#[test]
+fn test_ante_posted_each_hand() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 2,
+        structure: BettingStructure::NoLimit,
+        ante: 5,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // Antes are dead money on top of the blinds
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.pot, 13);
+    assert_eq!(game.player1_stack, 94);
+    assert_eq!(game.player2_stack, 93);
+    assert_eq!(game.player1_bet, 1);
+
+    // Next hand posts the ante again
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.hand_number, 2);
+    assert_eq!(game.pot, 13);
+    assert_eq!(game.player1_stack, 94 - 5 - 2);
+    assert_eq!(game.player2_stack, 93 + 13 - 5 - 1);
+}
+
+#[test]
+fn test_position_alternates_between_hands() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 3,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // Hand 1: player1 has the button, acts first preflop
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+
+    // Out-of-position player2 opens the flop
+    assert_eq!(pocker.get_game(&session_id).unwrap().current_actor, 1);
+    pocker.player_action(&session_id, &player2, &Action::Bet(10));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    // Hand 2: button moves, player2 acts first preflop
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.dealer, 1);
+    assert_eq!(game.current_actor, 1);
+}
+
+#[test]
+fn test_tournament_blinds_escalate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let mut levels = Vec::new(&env);
+    levels.push_back(BlindLevel { small_blind: 1, big_blind: 2, ante: 0 });
+    levels.push_back(BlindLevel { small_blind: 5, big_blind: 10, ante: 1 });
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 10,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: Some(TournamentConfig { hands_per_level: 1, levels }),
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    assert_eq!(pocker.get_game(&session_id).unwrap().blind_level, 0);
+
+    // Hand 1: player1 folds the small blind
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    // Hand 2 moves up a level
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.blind_level, 1);
+    assert_eq!(game.big_blind, 10);
+    assert_eq!(game.pot, 5 + 10 + 2);
+}
+
+#[test]
+fn test_showdown_timeout_forfeits_first_to_show() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Player1 calls, player2 bets every street and player1 calls down
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Bet(2));
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.deal_turn(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_river(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    assert_eq!(pocker.get_game(&session_id).unwrap().phase, Phase::Showdown);
+
+    // Too early
+    assert_eq!(
+        pocker.try_claim_showdown_timeout(&session_id, &player1),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+
+    // Player2 was the last aggressor and never proved: player1 takes the pot
+    let winner = pocker.claim_showdown_timeout(&session_id, &player1);
+    assert_eq!(winner, player1);
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 104);
+}
+
+#[test]
+fn test_reveal_hand_binds_to_own_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+
+    // Check it down to the showdown
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    for street in 0..3 {
+        match street {
+            0 => pocker.deal_flop(&session_id),
+            1 => pocker.deal_turn(&session_id),
+            _ => pocker.deal_river(&session_id),
+        };
+        pocker.player_action(&session_id, &player2, &Action::Check);
+        pocker.player_action(&session_id, &player1, &Action::Check);
+    }
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Showdown);
+
+    let proof = Groth16Proof {
+        pi_a: BytesN::from_array(&env, &[0u8; 64]),
+        pi_b: BytesN::from_array(&env, &[1u8; 128]),
+        pi_c: BytesN::from_array(&env, &[2u8; 64]),
+    };
+    let signals = |hole: &Bytes, board: Bytes| {
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(board);
+        public_signals.push_back(Bytes::from_slice(&env, &[5u8]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        public_signals
+    };
+    let board = game.community_commitment.clone().unwrap();
+
+    // A player can't prove the opponent's hand or a different board
+    assert_eq!(
+        pocker.try_reveal_hand(&session_id, &player1, &proof, &signals(&commitment2, board.clone())),
+        Err(Ok(Error::InvalidCommitment))
+    );
+    assert_eq!(
+        pocker.try_reveal_hand(
+            &session_id,
+            &player1,
+            &proof,
+            &signals(&commitment1, Bytes::from_slice(&env, b"other board"))
+        ),
+        Err(Ok(Error::InvalidCommitment))
+    );
+    assert_eq!(
+        pocker.try_reveal_hand(&session_id, &outsider, &proof, &signals(&commitment1, board)),
+        Err(Ok(Error::NotPlayer))
+    );
+}
+
+#[test]
+fn test_used_proof_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_turn(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_river(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    assert_eq!(pocker.get_phase(&session_id), Phase::Showdown);
+
+    let proof = Groth16Proof {
+        pi_a: BytesN::from_array(&env, &[0u8; 64]),
+        pi_b: BytesN::from_array(&env, &[1u8; 128]),
+        pi_c: BytesN::from_array(&env, &[2u8; 64]),
+    };
+
+    // Mark the proof as already accepted for this session
+    env.as_contract(&contract_id, || {
+        let key = DataKey::ProofNullifiers(session_id);
+        let nullifiers = vec![&env, PockerContract::proof_hash(&env, &proof)];
+        env.storage().temporary().set(&key, &nullifiers);
+    });
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let mut public_signals = Vec::new(&env);
+    public_signals.push_back(commitment1);
+    public_signals.push_back(game.community_commitment.unwrap());
+    public_signals.push_back(Bytes::from_slice(&env, &[5u8]));
+    public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+    public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+
+    assert_eq!(
+        pocker.try_reveal_hand(&session_id, &player1, &proof, &public_signals),
+        Err(Ok(Error::ProofAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_hand_history_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    pocker.player_action(&session_id, &player1, &Action::Raise(6));
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Fold);
+
+    assert_eq!(pocker.get_history_len(&session_id), 3);
+
+    let page = pocker.get_history(&session_id, &0u32, &2u32);
+    assert_eq!(page.len(), 2);
+    let first = page.get(0).unwrap();
+    assert_eq!(first.actor, player1);
+    assert_eq!(first.action, Action::Raise(6));
+    assert_eq!(first.amount, 5);
+    assert_eq!(first.phase, Phase::Preflop);
+    assert_eq!(page.get(1).unwrap().amount, 4);
+
+    let page = pocker.get_history(&session_id, &2u32, &10u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().action, Action::Fold);
+    assert_eq!(page.get(0).unwrap().phase, Phase::Flop);
+}
+
+#[test]
+fn test_run_it_twice_second_board() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Not available until someone is all-in
+    assert!(pocker.try_opt_in_run_it_twice(&session_id, &player1).is_err());
+
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.deal_flop(&session_id);
+
+    pocker.opt_in_run_it_twice(&session_id, &player1);
+    assert!(pocker.get_game(&session_id).unwrap().second_board_commitment.is_none());
+    pocker.opt_in_run_it_twice(&session_id, &player2);
+
+    pocker.deal_turn(&session_id);
+    pocker.deal_river(&session_id);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Showdown);
+    assert!(game.second_board_commitment.is_some());
+    assert_eq!(game.second_board.len(), 5);
+
+    // Flop is shared, turn and river are fresh cards
+    for i in 0..3u32 {
+        assert_eq!(game.second_board.get(i), game.community_cards.get(i));
+    }
+    for i in 3..5u32 {
+        let card = game.second_board.get(i).unwrap();
+        assert!(!game.community_cards.contains(card));
+    }
+}
+
+#[test]
+fn test_run_it_twice_split_boards_chop() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let testnet = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"Test SDF Network ; September 2015"))
+        .to_array();
+    env.ledger().set_network_id(testnet);
+    pocker.set_dev_mode(&true);
+
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.deal_flop(&session_id);
+    pocker.opt_in_run_it_twice(&session_id, &player1);
+    pocker.opt_in_run_it_twice(&session_id, &player2);
+    pocker.deal_turn(&session_id);
+    pocker.deal_river(&session_id);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let reveal = |hole: &Bytes, board: &Bytes, ranking: u8, seed: u8| {
+        let proof = Groth16Proof {
+            pi_a: BytesN::from_array(&env, &[seed; 64]),
+            pi_b: BytesN::from_array(&env, &[seed; 128]),
+            pi_c: BytesN::from_array(&env, &[seed; 64]),
+        };
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(board.clone());
+        public_signals.push_back(Bytes::from_slice(&env, &[ranking]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        (proof, public_signals)
+    };
+    let first_board = game.community_commitment.clone().unwrap();
+    let second_board = game.second_board_commitment.clone().unwrap();
+
+    // Player1 takes the first board, player2 the second
+    let (proof, signals) = reveal(&commitment1, &first_board, 5, 1);
+    pocker.reveal_hand(&session_id, &player1, &proof, &signals);
+    let (proof, signals) = reveal(&commitment2, &first_board, 3, 2);
+    pocker.reveal_hand(&session_id, &player2, &proof, &signals);
+    let (proof, signals) = reveal(&commitment1, &second_board, 2, 3);
+    pocker.reveal_hand(&session_id, &player1, &proof, &signals);
+    let (proof, signals) = reveal(&commitment2, &second_board, 6, 4);
+    assert_eq!(
+        pocker.reveal_hand(&session_id, &player2, &proof, &signals),
+        Some(player2.clone())
+    );
+
+    // Split boards: the session ends without a winner and the hub isn't told player1 won
+    assert_eq!(env.events().all(), Vec::<(Address, Vec<Val>, Val)>::new(&env));
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+}
+
+#[test]
+fn test_settle_all_in_with_agreed_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // No early settlement while betting is still open
+    assert_eq!(
+        pocker.try_settle_all_in(&session_id, &5_000u32),
+        Err(Ok(Error::NotInPhase))
+    );
+
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::Call);
+
+    assert_eq!(
+        pocker.try_settle_all_in(&session_id, &20_000u32),
+        Err(Ok(Error::InvalidSplit))
+    );
+
+    // 70/30 chop of the 200 pot
+    pocker.settle_all_in(&session_id, &7_000u32);
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 140);
+    assert_eq!(game.player2_stack, 60);
+    assert_eq!(game.winner, Some(player1.clone()));
+
+    // An even split leaves the session without a winner
+    let session_id = 3u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.settle_all_in(&session_id, &5_000u32);
+
+    assert_eq!(env.events().all(), Vec::<(Address, Vec<Val>, Val)>::new(&env));
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(game.winner, None);
+}
+
+#[test]
+#[should_panic(expected = "AlreadyCommitted")]
+fn test_cannot_commit_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 1u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+
+    let commitment = Bytes::from_slice(&env, b"commitment_hash");
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment);
+
+    // Try to commit again
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment);
+}
+
+#[test]
+fn test_reveal_hand() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 1u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+
+    // Both players commit hole cards
+    let commitment1 = Bytes::from_slice(&env, b"commitment1_hash");
+    let commitment2 = Bytes::from_slice(&env, b"commitment2_hash");
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+
+    // Create mock proof (in real game, this would be a valid ZK proof)
+    let proof_pi_a = BytesN::from_array(&env, &[0u8; 64]);
+    let proof_pi_b = BytesN::from_array(&env, &[1u8; 128]);
+    let proof_pi_c = BytesN::from_array(&env, &[2u8; 64]);
+
+    let proof = Groth16Proof {
+        pi_a: proof_pi_a,
+        pi_b: proof_pi_b,
+        pi_c: proof_pi_c,
+    };
+
+    // Create public signals for player1's hand
+    // [0] = player1_hole_commitment
+    // [1] = community_commitment
+    // [2] = player1_ranking (e.g., 5 = Flush)
+    // [3] = session_id
+    // [4] = proof_nonce
+    let community_commitment = pocker.get_game(&session_id).unwrap().community_commitment.unwrap();
+    let mut public_signals = Vec::new(&env);
+    public_signals.push_back(commitment1.clone());
+    public_signals.push_back(community_commitment);
+    public_signals.push_back(Bytes::from_slice(&env, &[5u8])); // player1 ranking
+    public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+    let nonce = pocker.get_game(&session_id).unwrap().proof_nonce;
+    public_signals.push_back(Bytes::from_slice(&env, &nonce.to_be_bytes()));
+
+    // Note: This will fail without a valid verification key
+    // In production, you would set the verification key first
+    // For this test, we're just verifying the flow structure
+    
+    // Uncomment when verification key is set:
+    // assert_eq!(pocker.reveal_hand(&session_id, &player1, &proof, &public_signals), None);
+    // ...player2 reveals a weaker hand the same way...
+    // assert_eq!(pocker.reveal_hand(&session_id, &player2, &proof2, &signals2), Some(player1.clone()));
+    
+    // let game = pocker.get_game(&session_id).unwrap();
+    // assert_eq!(game.winner, Some(player1));
+    // assert_eq!(game.phase, Phase::Complete);
+}
+
+#[test]
+#[should_panic(expected = "NotInPhase")]
+fn test_cannot_reveal_before_commit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 1u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+
+    // Try to reveal without committing
+    let proof = Groth16Proof {
+        pi_a: Vec::new(&env),
+        pi_b: Vec::new(&env),
+        pi_c: Vec::new(&env),
+    };
+    let public_signals = Vec::new(&env);
+
+    pocker.reveal_hand(&session_id, &player1, &proof, &public_signals);
+}
+
+#[test]
+fn test_admin_functions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let (game_hub_id, _game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+
+    // Test set_admin
+    pocker.set_admin(&new_admin);
+    assert_eq!(pocker.get_admin(), new_admin);
+
+    // Test set_hub
+    let new_hub = Address::generate(&env);
+    pocker.set_hub(&new_hub);
+    assert_eq!(pocker.get_hub(), new_hub);
+}
+
+#[test]
+fn test_rake_collection() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // 5% rake
+    pocker.set_rake(&500u32, &treasury);
+    assert_eq!(pocker.get_rake_bps(), 500);
+    assert_eq!(pocker.get_treasury(), Some(treasury.clone()));
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    let token = soroban_sdk::token::TokenClient::new(&env, &sac.address());
+    token_admin.mint(&player1, &100);
+    token_admin.mint(&player2, &100);
+
+    let mut config = default_config();
+    config.small_blind = 10;
+    config.big_blind = 20;
+
+    // Hub-points sessions hold no tokens, so they aren't raked
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&1u32, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&1u32, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&1u32, &player1, &Action::Fold);
+    assert_eq!(pocker.get_game(&1u32).unwrap().player2_stack, 80 + 30);
+
+    config.escrow_token = Some(sac.address());
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Player1 (SB) folds: pot of 30 pays 1 rake, 29 to player2
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player2_stack, 80 + 29);
+    assert_eq!(pocker.get_accumulated_rake(&sac.address()), 1);
+    assert_eq!(token.balance(&contract_id), 1);
+
+    // Withdrawing moves the rake to the treasury
+    assert_eq!(pocker.withdraw_rake(&sac.address()), 1);
+    assert_eq!(pocker.get_accumulated_rake(&sac.address()), 0);
+    assert_eq!(token.balance(&treasury), 1);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(pocker.withdraw_rake(&sac.address()), 0);
+}
+
+#[test]
+#[should_panic(expected = "InvalidConfig")]
+fn test_rake_above_maximum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (game_hub_id, _game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    pocker.set_rake(&5_000u32, &Address::generate(&env));
+}
+
+#[test]
+fn test_versioned_verification_keys() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    pocker.set_verification_key(&1u32, &dummy_verification_key(&env));
+    assert_eq!(pocker.get_current_vk_version(), 1);
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+
+    // Upgrading the circuit doesn't move in-flight games to the new key
+    pocker.set_verification_key(&2u32, &dummy_verification_key(&env));
+    pocker.start_game(&2u32, &player1, &player2, &100i128, &100i128, &default_config());
+
+    assert_eq!(pocker.get_game(&1u32).unwrap().vk_version, 1);
+    assert_eq!(pocker.get_game(&2u32).unwrap().vk_version, 2);
+    assert!(pocker.get_verification_key(&1u32).is_some());
+    assert!(pocker.get_verification_key(&3u32).is_none());
+}
+
+#[test]
+fn test_player_session_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.start_game(&2u32, &player1, &player3, &100i128, &100i128, &default_config());
+
+    let sessions = pocker.get_player_sessions(&player1);
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions.get(0), Some(1));
+    assert_eq!(sessions.get(1), Some(2));
+    assert_eq!(pocker.get_player_sessions(&player3).len(), 1);
+    assert_eq!(pocker.get_player_sessions(&admin).len(), 0);
+}
+
+#[test]
+fn test_proof_nonce_unique_per_hand() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.start_game(&2u32, &player1, &player2, &100i128, &100i128, &default_config());
+
+    let nonce1 = pocker.get_game(&1u32).unwrap().proof_nonce;
+    let nonce2 = pocker.get_game(&2u32).unwrap().proof_nonce;
+    assert_ne!(nonce1, nonce2);
+}
+
+#[test]
+fn test_game_summary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    pocker.start_game(&2u32, &player1, &player2, &100i128, &100i128, &default_config());
+
+    let summary = pocker.get_summary(&2u32);
+    assert_eq!(summary.phase, Phase::Commit);
+    assert_eq!(summary.pot, 3);
+    assert_eq!(summary.player1_stack, 99);
+    assert_eq!(summary.player2_stack, 98);
+    assert_eq!(summary.current_actor, 0);
+    assert!(summary.winner.is_none());
+    assert!(pocker.try_get_summary(&999u32).is_err());
+}
+
+#[test]
+fn test_buy_in_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // Zero buy-ins are rejected even without configured bounds
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &0i128, &100i128, &default_config()),
+        Err(Ok(Error::InvalidStake))
+    );
+
+    pocker.set_buy_in_bounds(&50i128, &200i128);
+    assert_eq!(pocker.get_buy_in_bounds(), (50, 200));
+
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &10i128, &100i128, &default_config()),
+        Err(Ok(Error::InvalidBuyIn))
+    );
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &100i128, &500i128, &default_config()),
+        Err(Ok(Error::InvalidBuyIn))
+    );
+    pocker.start_game(&1u32, &player1, &player2, &50i128, &200i128, &default_config());
+}
+
+#[test]
+fn test_pause_blocks_start_and_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    pocker.pause();
+    assert!(pocker.is_paused());
+    assert_eq!(
+        pocker.try_start_game(&3u32, &player1, &player2, &100i128, &100i128, &default_config()),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        pocker.try_player_action(&session_id, &player1, &Action::Call),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    pocker.unpause();
+    assert!(!pocker.is_paused());
+    pocker.player_action(&session_id, &player1, &Action::Call);
+}
+
+#[test]
+fn test_schema_version_and_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+    assert_eq!(pocker.get_schema_version(), 2);
+
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+
+    // Already on the current layout: migrate leaves the game as it is
+    let game = pocker.get_game(&1u32).unwrap();
+    assert_eq!(pocker.migrate(&1u32), 2);
+    assert_eq!(pocker.get_game(&1u32).unwrap(), game);
+    assert_eq!(pocker.try_migrate(&999u32), Err(Ok(Error::GameNotFound)));
+
+    // A game stored by the original deployment (baseline layout, no recorded
+    // version) is rewritten
+    let old = GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100,
+        player2_points: 100,
+        player1_stack: 90,
+        player2_stack: 80,
+        player1_bet: 10,
+        player2_bet: 20,
+        pot: 30,
+        player1_hole_commitment: Some(Bytes::from_slice(&env, b"c1")),
+        player2_hole_commitment: Some(Bytes::from_slice(&env, b"c2")),
+        community_cards: Vec::new(&env),
+        community_commitment: Some(Bytes::from_slice(&env, &[0u8; 32])),
+        community_revealed: 0,
+        current_actor: 0,
+        last_action: Action::Raise(20),
+        last_raise_amount: 10,
+        actions_this_round: 1,
+        player1_revealed: false,
+        player2_revealed: false,
+        player1_ranking: None,
+        player2_ranking: None,
+        winner: None,
+        phase: Phase::Preflop,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().temporary().set(&DataKey::Game(2), &old);
+    });
+    assert!(pocker.try_get_game(&2u32).is_err());
+
+    assert_eq!(pocker.migrate(&2u32), 2);
+    let game = pocker.get_game(&2u32).unwrap();
+    assert_eq!(game.player1_stack, 90);
+    assert_eq!(game.player2_bet, 20);
+    assert_eq!(game.pot, 30);
+    assert_eq!(game.last_action, Action::Raise(20));
+    assert_eq!(game.phase, Phase::Preflop);
+    assert_eq!(game.structure, BettingStructure::NoLimit);
+    assert_eq!(game.raise_cap, 0);
+    assert_eq!((game.hand_number, game.max_hands), (1, 1));
+    assert!(game.betting_reopened);
+    assert_eq!(game.escrow_token, None);
+    assert_eq!(game.player1_bounty, 0);
+
+    // Migrated games play on under the current code
+    pocker.player_action(&2u32, &player1, &Action::Call);
+    assert_eq!(pocker.migrate(&2u32), 2);
+}
+
+#[test]
+fn test_extend_game_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&1u32, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&1u32, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&1u32, &player1, &Action::Call);
+    let before = pocker.get_game(&1u32).unwrap();
+
+    // Let every key of the session age, then extend from player2
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    pocker.extend_game_ttl(&1u32, &player2, &0u32);
+    assert_eq!(pocker.get_game(&1u32).unwrap(), before);
+
+    env.as_contract(&contract_id, || {
+        let keys = [
+            DataKey::Game(1),
+            DataKey::GameSchema(1),
+            DataKey::HistoryLen(1),
+            DataKey::HistoryEntry(1, 0),
+            DataKey::PlayerSessions(player1.clone()),
+            DataKey::PlayerSessions(player2.clone()),
+        ];
+        for key in keys {
+            assert_eq!(env.storage().temporary().get_ttl(&key), GAME_TTL_LEDGERS);
+        }
+    });
+
+    // Only the session's players can extend it
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        pocker.try_extend_game_ttl(&1u32, &outsider, &0u32),
+        Err(Ok(Error::NotPlayer))
+    );
+    assert_eq!(
+        pocker.try_extend_game_ttl(&999u32, &player1, &0u32),
+        Err(Ok(Error::GameNotFound))
+    );
+}
+
+#[test]
+fn test_compute_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let hole1 = vec![&env, 0u32, 12];
+    let hole2 = vec![&env, 25u32, 51];
+    let salt = U256::from_u32(&env, 12345);
+    let commitment1 = pocker.compute_commitment(&hole1, &salt);
+    let commitment2 = pocker.compute_commitment(&hole2, &salt);
+
+    // Deterministic, 32 bytes, and bound to both the cards and the salt
+    assert_eq!(commitment1.len(), 32);
+    assert_eq!(pocker.compute_commitment(&hole1, &salt), commitment1);
+    assert_ne!(commitment1, commitment2);
+    assert_ne!(
+        pocker.compute_commitment(&hole1, &U256::from_u32(&env, 54321)),
+        commitment1
+    );
+
+    // Only 2 in-deck hole cards can be committed to
+    let five = vec![&env, 0u32, 12, 25, 38, 51];
+    assert_eq!(
+        pocker.try_compute_commitment(&five, &salt),
+        Err(Ok(Error::InvalidCommitment))
+    );
+    let out_of_deck = vec![&env, 0u32, 52];
+    assert_eq!(
+        pocker.try_compute_commitment(&out_of_deck, &salt),
+        Err(Ok(Error::InvalidCommitment))
+    );
+
+    // The computed value is what the showdown accepts as the hole commitment
+    let testnet = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"Test SDF Network ; September 2015"))
+        .to_array();
+    env.ledger().set_network_id(testnet);
+    pocker.set_dev_mode(&true);
+
+    let session_id = 1u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    for street in 0..3 {
+        match street {
+            0 => pocker.deal_flop(&session_id),
+            1 => pocker.deal_turn(&session_id),
+            _ => pocker.deal_river(&session_id),
+        };
+        pocker.player_action(&session_id, &player2, &Action::Check);
+        pocker.player_action(&session_id, &player1, &Action::Check);
+    }
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let proof = Groth16Proof {
+        pi_a: BytesN::from_array(&env, &[0u8; 64]),
+        pi_b: BytesN::from_array(&env, &[1u8; 128]),
+        pi_c: BytesN::from_array(&env, &[2u8; 64]),
+    };
+    let signals = |hole: &Bytes| {
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(game.community_commitment.clone().unwrap());
+        public_signals.push_back(Bytes::from_slice(&env, &[5u8]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        public_signals
+    };
+
+    // A commitment to other cards is rejected; the computed one is accepted
+    let other = pocker.compute_commitment(&vec![&env, 0u32, 13], &salt);
+    assert_eq!(
+        pocker.try_reveal_hand(&session_id, &player1, &proof, &signals(&other)),
+        Err(Ok(Error::InvalidCommitment))
+    );
+    assert_eq!(pocker.reveal_hand(&session_id, &player1, &proof, &signals(&commitment1)), None);
+}
+
+#[test]
+fn test_reclaim_after_commit_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+
+    assert_eq!(
+        pocker.try_reclaim(&session_id, &player1),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    assert_eq!(
+        pocker.try_reclaim(&session_id, &player2),
+        Err(Ok(Error::NotCommitted))
+    );
+    pocker.reclaim(&session_id, &player1);
+
+    // The hand is voided: blinds are returned and nobody wins
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(game.pot, 0);
+    assert_eq!(
+        pocker.try_reclaim(&session_id, &player1),
+        Err(Ok(Error::NotInPhase))
+    );
+
+    // Player2 can reclaim a hand player1 abandoned, and the hub isn't told
+    // player1 won: a voided session reports nothing
+    let abandoned = 4u32;
+    pocker.start_game(&abandoned, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&abandoned, &player2, &Bytes::from_slice(&env, b"c2"));
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    pocker.reclaim(&abandoned, &player2);
+    assert_eq!(env.events().all(), Vec::<(Address, Vec<Val>, Val)>::new(&env));
+    let game = pocker.get_game(&abandoned).unwrap();
+    assert_eq!(game.winner, None);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(
+        pocker.try_retry_end_game(&abandoned),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+
+    // In escrow mode neither buy-in is forfeited
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    let token = soroban_sdk::token::TokenClient::new(&env, &sac.address());
+    token_admin.mint(&player1, &100);
+    token_admin.mint(&player2, &100);
+
+    let mut config = default_config();
+    config.escrow_token = Some(sac.address());
+    let escrow_session = 3u32;
+    pocker.start_game(&escrow_session, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&escrow_session, &player1, &Bytes::from_slice(&env, b"c1"));
+    assert_eq!(token.balance(&contract_id), 200);
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    pocker.reclaim(&escrow_session, &player1);
+
+    assert_eq!(pocker.get_game(&escrow_session).unwrap().winner, None);
+    assert_eq!(token.balance(&player1), 100);
+    assert_eq!(token.balance(&player2), 100);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_sit_out_and_resume() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // Single-hand sessions can't sit out
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+    assert_eq!(
+        pocker.try_sit_out(&1u32, &player1),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    let config = TableConfig {
+        small_blind: 1,
+        big_blind: 2,
+        max_hands: 10,
+        structure: BettingStructure::NoLimit,
+        ante: 0,
+        tournament: None,
+        name: None,
+        escrow_token: None,
+        bounty_bps: 0,
+        parent: None,
+        raise_cap: 0,
+        matched_stakes: false,
+        max_rebuys: 0,
+    };
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // Sitting out mid-hand and resuming before it ends changes nothing
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.sit_out(&session_id, &player2);
+    pocker.resume(&session_id, &player2);
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Commit);
+    assert_eq!(game.hand_number, 2);
+    assert!(!game.player2_sitting_out);
+
+    // Sitting out during Commit folds only this hand
+    pocker.sit_out(&session_id, &player2);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Commit);
+    assert_eq!(game.hand_number, 3);
+    assert_eq!(game.player2_missed_hands, 1);
+    assert!(game.player2_sitting_out);
+
+    // Later hands wait for the opponent to fold them
+    assert_eq!(
+        pocker.try_fold_sitting_out(&session_id, &player2),
+        Err(Ok(Error::NotSittingOut))
+    );
+    pocker.fold_sitting_out(&session_id, &player1);
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.hand_number, 4);
+    assert_eq!(game.player2_missed_hands, 2);
+
+    // Resuming in between keeps the session alive and resets the count
+    pocker.resume(&session_id, &player2);
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Commit);
+    assert_eq!(game.player2_missed_hands, 0);
+    assert_eq!(
+        pocker.try_fold_sitting_out(&session_id, &player1),
+        Err(Ok(Error::NotSittingOut))
+    );
+
+    // MAX_SIT_OUT_HANDS consecutive folds forfeit the session
+    pocker.sit_out(&session_id, &player2);
+    pocker.fold_sitting_out(&session_id, &player1);
+    pocker.fold_sitting_out(&session_id, &player1);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.hand_number, 6);
+    assert_eq!(game.player2_missed_hands, 3);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.player1_stack + game.player2_stack, 200);
+    assert!(game.player1_stack > game.player2_stack);
+    assert_eq!(
+        pocker.try_resume(&session_id, &player2),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+}
+
+#[test]
+fn test_table_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let mut config = default_config();
+    config.name = Some(Symbol::new(&env, "high_rollers"));
+    config.structure = BettingStructure::PotLimit;
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &config);
+
+    let metadata = pocker.get_table_metadata(&1u32);
+    assert_eq!(metadata.name, Some(Symbol::new(&env, "high_rollers")));
+    assert_eq!(metadata.small_blind, 1);
+    assert_eq!(metadata.big_blind, 2);
+    assert_eq!(metadata.structure, BettingStructure::PotLimit);
+    assert_eq!(metadata.hand_number, 1);
+
+    // Name is optional
+    pocker.start_game(&2u32, &player1, &player2, &100i128, &100i128, &default_config());
+    assert_eq!(pocker.get_table_metadata(&2u32).name, None);
+    assert_eq!(
+        pocker.try_get_table_metadata(&999u32),
+        Err(Ok(Error::GameNotFound))
+    );
+}
+
+#[test]
+fn test_reveal_hand_and_payout_pays_escrowed_stacks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let testnet = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"Test SDF Network ; September 2015"))
+        .to_array();
+    env.ledger().set_network_id(testnet);
+    pocker.set_dev_mode(&true);
+
+    let proof = Groth16Proof {
+        pi_a: BytesN::from_array(&env, &[0u8; 64]),
+        pi_b: BytesN::from_array(&env, &[1u8; 128]),
+        pi_c: BytesN::from_array(&env, &[2u8; 64]),
+    };
+
+    // Hub-points sessions hold no funds to pay out
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &default_config());
+    assert_eq!(
+        pocker.try_reveal_hand_and_payout(&1u32, &player1, &proof, &Vec::new(&env)),
+        Err(Ok(Error::PayoutNotConfigured))
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    let token = soroban_sdk::token::TokenClient::new(&env, &sac.address());
+    token_admin.mint(&player1, &100);
+    token_admin.mint(&player2, &100);
+
+    let mut config = default_config();
+    config.escrow_token = Some(sac.address());
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_turn(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_river(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let reveal = |hole: &Bytes, ranking: u8, seed: u8| {
+        let proof = Groth16Proof {
+            pi_a: BytesN::from_array(&env, &[seed; 64]),
+            pi_b: BytesN::from_array(&env, &[seed; 128]),
+            pi_c: BytesN::from_array(&env, &[seed; 64]),
+        };
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(game.community_commitment.clone().unwrap());
+        public_signals.push_back(Bytes::from_slice(&env, &[ranking]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        (proof, public_signals)
+    };
+
+    // The first reveal doesn't end the session, so it is rolled back
+    let (proof1, signals1) = reveal(&commitment1, 5, 1);
+    assert_eq!(
+        pocker.try_reveal_hand_and_payout(&session_id, &player1, &proof1, &signals1),
+        Err(Ok(Error::SessionNotOver))
+    );
+    assert_eq!(pocker.reveal_hand(&session_id, &player1, &proof1, &signals1), None);
+
+    // The final reveal pays each player their own stack, not the whole table
+    let (proof2, signals2) = reveal(&commitment2, 3, 2);
+    assert_eq!(
+        pocker.reveal_hand_and_payout(&session_id, &player2, &proof2, &signals2),
+        Some(player1.clone())
+    );
+    assert_eq!(token.balance(&player1), 102);
+    assert_eq!(token.balance(&player2), 98);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_escrow_mode_pays_out_stacks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    let token = soroban_sdk::token::TokenClient::new(&env, &sac.address());
+    token_admin.mint(&player1, &100);
+    token_admin.mint(&player2, &100);
+
+    let mut config = default_config();
+    config.escrow_token = Some(sac.address());
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // Buy-ins are held by the contract
+    assert_eq!(token.balance(&contract_id), 200);
+    assert_eq!(token.balance(&player1), 0);
+
+    // Player1 folds the small blind: both stacks are paid out
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    assert_eq!(pocker.get_game(&session_id).unwrap().phase, Phase::Complete);
+    assert_eq!(token.balance(&player1), 99);
+    assert_eq!(token.balance(&player2), 101);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_knockout_bounty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // Bounties need a multi-hand session
+    let mut config = default_config();
+    config.bounty_bps = 2_000;
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &100i128, &100i128, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    config.max_hands = 10;
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // 20% of each buy-in is set aside as a bounty
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player1_bounty, 20);
+    assert_eq!(game.player1_stack + game.player1_bet, 80);
+
+    // Player1 shoves, player2 calls, player1 never proves and is busted
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::AllIn);
+    pocker.player_action(&session_id, &player2, &Action::Call);
+    pocker.deal_flop(&session_id);
+    pocker.deal_turn(&session_id);
+    pocker.deal_river(&session_id);
+    env.ledger().with_mut(|li| li.sequence_number += 17_281);
+    pocker.claim_showdown_timeout(&session_id, &player2);
+
+    // Player2 collects both bounties with the pot
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.bounty_winner, Some(1));
+    assert_eq!(game.player1_stack, 0);
+    assert_eq!(game.player2_stack, 200);
+    assert_eq!(game.player1_bounty + game.player2_bounty, 0);
+}
+
+#[test]
+fn test_result_reported_to_parent_tournament() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+    let tournament_id = env.register_contract(None, mock_tournament::MockTournament);
+    let tournament = mock_tournament::MockTournamentClient::new(&env, &tournament_id);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let mut config = default_config();
+    config.parent = Some(ParentTournament {
+        contract: tournament_id.clone(),
+        tournament_id: 7,
+    });
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    assert_eq!(tournament.result(&session_id), None);
+
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    assert_eq!(
+        tournament.result(&session_id),
+        Some((7, player2.clone(), player1.clone()))
+    );
+}
+
+#[test]
+fn test_phase_and_current_actor_views() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    assert_eq!(pocker.get_phase(&session_id), Phase::Commit);
+
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    assert_eq!(pocker.get_phase(&session_id), Phase::Preflop);
+    assert_eq!(pocker.get_current_actor(&session_id), 0);
+
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    assert_eq!(pocker.get_current_actor(&session_id), 1);
+
+    assert_eq!(pocker.try_get_phase(&999u32), Err(Ok(Error::GameNotFound)));
+    assert_eq!(pocker.try_get_current_actor(&999u32), Err(Ok(Error::GameNotFound)));
+}
+
+#[test]
+fn test_retry_end_game_after_hub_failure() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+
+    // Point the hub at a contract without end_game so the call fails
+    let broken_hub = env.register_contract(None, mock_tournament::MockTournament);
+    pocker.set_hub(&broken_hub);
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    // The game still completed
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, Some(player2.clone()));
+
+    // Once the hub is healthy the result can be reported, exactly once
+    pocker.set_hub(&game_hub_id);
+    pocker.retry_end_game(&session_id);
+    assert_eq!(
+        pocker.try_retry_end_game(&session_id),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+}
+
+#[test]
+fn test_force_end_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::Call);
+
+    // Nothing to apply until proposed, and only after the delay
+    assert_eq!(
+        pocker.try_force_end(&session_id, &ForceOutcome::Draw),
+        Err(Ok(Error::InvalidConfig))
+    );
+    pocker.propose_force_end(&session_id, &ForceOutcome::Draw);
+    assert_eq!(
+        pocker.try_force_end(&session_id, &ForceOutcome::Draw),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 51_840);
+    assert_eq!(
+        pocker.try_force_end(&session_id, &ForceOutcome::Player1Wins),
+        Err(Ok(Error::InvalidConfig))
+    );
+    pocker.force_end(&session_id, &ForceOutcome::Draw);
+
+    // A draw hands every chip back; with even stacks the hub gets no result
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("FORCEEND"), session_id).into_val(&env),
+                ForceOutcome::Draw.into_val(&env)
+            )
+        ]
+    );
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(game.winner, None);
+    assert_eq!(
+        pocker.try_force_end(&session_id, &ForceOutcome::Draw),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+}
+
+#[test]
+fn test_player_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+    assert_eq!(pocker.get_player_stats(&player1), PlayerStats::default());
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+
+    assert_eq!(
+        pocker.get_player_stats(&player1),
+        PlayerStats { hands_played: 1, hands_won: 0, total_pot_won: 0, folds: 1 }
+    );
+    assert_eq!(
+        pocker.get_player_stats(&player2),
+        PlayerStats { hands_played: 1, hands_won: 1, total_pot_won: 3, folds: 0 }
+    );
+}
+
+#[test]
+fn test_dev_mode_only_on_test_networks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    // Unknown network: refused
+    assert_eq!(pocker.try_set_dev_mode(&true), Err(Ok(Error::InvalidConfig)));
+
+    let testnet = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"Test SDF Network ; September 2015"))
+        .to_array();
+    env.ledger().set_network_id(testnet);
+    pocker.set_dev_mode(&true);
+    assert!(pocker.get_dev_mode());
+
+    // Check down to the showdown and reveal both hands without real proofs
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_turn(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_river(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let reveal = |hole: &Bytes, ranking: u8, seed: u8| {
+        let proof = Groth16Proof {
+            pi_a: BytesN::from_array(&env, &[seed; 64]),
+            pi_b: BytesN::from_array(&env, &[seed; 128]),
+            pi_c: BytesN::from_array(&env, &[seed; 64]),
+        };
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(game.community_commitment.clone().unwrap());
+        public_signals.push_back(Bytes::from_slice(&env, &[ranking]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        (proof, public_signals)
+    };
+
+    let (proof1, signals1) = reveal(&commitment1, 5, 1);
+    assert_eq!(pocker.reveal_hand(&session_id, &player1, &proof1, &signals1), None);
+    let (proof2, signals2) = reveal(&commitment2, 3, 2);
+    assert_eq!(
+        pocker.reveal_hand(&session_id, &player2, &proof2, &signals2),
+        Some(player1.clone())
+    );
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.player1_stack, 102);
+}
+
+#[test]
+fn test_equal_rankings_chop_the_pot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let testnet = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"Test SDF Network ; September 2015"))
+        .to_array();
+    env.ledger().set_network_id(testnet);
+    pocker.set_dev_mode(&true);
+
+    // Check down to the showdown with an even pot
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_hole_commitment(&session_id, &player1, &commitment1);
+    pocker.submit_hole_commitment(&session_id, &player2, &commitment2);
+    pocker.player_action(&session_id, &player1, &Action::Call);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.deal_flop(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_turn(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+    pocker.deal_river(&session_id);
+    pocker.player_action(&session_id, &player2, &Action::Check);
+    pocker.player_action(&session_id, &player1, &Action::Check);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    let reveal = |hole: &Bytes, ranking: u8, seed: u8| {
+        let proof = Groth16Proof {
+            pi_a: BytesN::from_array(&env, &[seed; 64]),
+            pi_b: BytesN::from_array(&env, &[seed; 128]),
+            pi_c: BytesN::from_array(&env, &[seed; 64]),
+        };
+        let mut public_signals = Vec::new(&env);
+        public_signals.push_back(hole.clone());
+        public_signals.push_back(game.community_commitment.clone().unwrap());
+        public_signals.push_back(Bytes::from_slice(&env, &[ranking]));
+        public_signals.push_back(Bytes::from_slice(&env, &session_id.to_be_bytes()));
+        public_signals.push_back(Bytes::from_slice(&env, &game.proof_nonce.to_be_bytes()));
+        (proof, public_signals)
+    };
+
+    // Both players show a pair: nobody wins the board
+    let (proof1, signals1) = reveal(&commitment1, 1, 1);
+    assert_eq!(pocker.reveal_hand(&session_id, &player1, &proof1, &signals1), None);
+    let (proof2, signals2) = reveal(&commitment2, 1, 2);
+    assert_eq!(pocker.reveal_hand(&session_id, &player2, &proof2, &signals2), None);
+
+    // The pot is split and the session ends without a winner, so the hub isn't told player1 won
+    assert_eq!(env.events().all(), Vec::<(Address, Vec<Val>, Val)>::new(&env));
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Complete);
+    assert_eq!(game.winner, None);
+    assert_eq!(game.player1_stack, 100);
+    assert_eq!(game.player2_stack, 100);
+    assert_eq!(pocker.get_player_stats(&player1).hands_won, 0);
+    assert_eq!(pocker.get_player_stats(&player2).hands_won, 0);
+}
+#[test]
+fn test_card_encoding_helpers() {
+    let env = Env::default();
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    // card = suit * 13 + rank
+    assert_eq!(pocker.card_to_rank_suit(&0), Card { rank: 0, suit: 0 });
+    assert_eq!(pocker.card_to_rank_suit(&13), Card { rank: 0, suit: 1 });
+    assert_eq!(pocker.card_to_rank_suit(&51), Card { rank: 12, suit: 3 });
+    assert_eq!(pocker.try_card_to_rank_suit(&52), Err(Ok(Error::InvalidCard)));
+
+    assert_eq!(pocker.hand_rank(&0), HandRank::HighCard);
+    assert_eq!(pocker.hand_rank(&5), HandRank::Flush);
+    assert_eq!(pocker.hand_rank(&9), HandRank::RoyalFlush);
+    assert_eq!(pocker.try_hand_rank(&10), Err(Ok(Error::InvalidHandRank)));
+}
+
+#[test]
+fn test_invalid_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &100i128, &-5i128, &default_config()),
+        Err(Ok(Error::InvalidStake))
+    );
+
+    // Matched stakes: both buy-ins must be equal
+    let mut config = default_config();
+    config.matched_stakes = true;
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &100i128, &50i128, &config),
+        Err(Ok(Error::InvalidStake))
+    );
+    pocker.start_game(&1u32, &player1, &player2, &100i128, &100i128, &config);
+}
+
+#[test]
+fn test_state_digest_tracks_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (_contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    let digest = pocker.get_state_digest(&session_id);
+    assert_eq!(pocker.get_state_digest(&session_id), digest);
+
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"c1"));
+    assert_ne!(pocker.get_state_digest(&session_id), digest);
+    assert_eq!(
+        pocker.try_get_state_digest(&999u32),
+        Err(Ok(Error::GameNotFound))
+    );
+}
+
+#[test]
+fn test_rebuy_between_hands() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    let token = soroban_sdk::token::TokenClient::new(&env, &sac.address());
+    token_admin.mint(&player1, &200);
+    token_admin.mint(&player2, &200);
+
+    // Rebuys need escrowed tokens
+    let mut config = default_config();
+    config.max_hands = 10;
+    config.max_rebuys = 1;
+    assert_eq!(
+        pocker.try_start_game(&1u32, &player1, &player2, &100i128, &100i128, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    config.escrow_token = Some(sac.address());
+    let session_id = 2u32;
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &config);
+
+    // Player1 folds hand 1, then posts the big blind in hand 2 (97 behind)
+    pocker.submit_hole_commitment(&session_id, &player1, &Bytes::from_slice(&env, b"h1c1"));
+    pocker.submit_hole_commitment(&session_id, &player2, &Bytes::from_slice(&env, b"h1c2"));
+    pocker.player_action(&session_id, &player1, &Action::Fold);
+    assert_eq!(pocker.get_game(&session_id).unwrap().player1_stack, 97);
+
+    // Can't go above the original buy-in
+    assert_eq!(
+        pocker.try_rebuy(&session_id, &player1, &2i128),
+        Err(Ok(Error::InvalidStake))
+    );
+    pocker.rebuy(&session_id, &player1, &1i128);
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.player1_stack, 98);
+    assert_eq!(game.player1_rebuys, 1);
+    assert_eq!(token.balance(&player1), 99);
+    assert_eq!(token.balance(&contract_id), 201);
+
+    // Rebuy limit reached
+    assert_eq!(
+        pocker.try_rebuy(&session_id, &player1, &1i128),
+        Err(Ok(Error::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_submit_both_commitments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let (game_hub_id, game_hub) = create_game_hub(&env);
+    let (contract_id, pocker) = create_pocker_contract(&env);
+
+    pocker.__constructor(&admin, &game_hub_id);
+    game_hub.initialize(&admin);
+
+    let session_id = 2u32;
+    let commitment1 = Bytes::from_slice(&env, b"c1");
+    let commitment2 = Bytes::from_slice(&env, b"c2");
+    pocker.start_game(&session_id, &player1, &player2, &100i128, &100i128, &default_config());
+    pocker.submit_both_commitments(&session_id, &commitment1, &commitment2);
+
+    // Each player authorized only their own commitment
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(
+        auths[1],
+        (
+            player2.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "submit_both_commitments"),
+                    (session_id, commitment2.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )
+    );
+
+    let game = pocker.get_game(&session_id).unwrap();
+    assert_eq!(game.phase, Phase::Preflop);
+    assert_eq!(game.player1_hole_commitment, Some(commitment1.clone()));
+    assert!(game.community_commitment.is_some());
+    assert_eq!(
+        pocker.try_submit_both_commitments(&session_id, &commitment1, &commitment2),
+        Err(Ok(Error::NotInPhase))
+    );
 }
 
 #[test]